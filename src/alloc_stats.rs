@@ -0,0 +1,205 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde::Serialize;
+
+/// System allocator wrapper counting live, peak and total allocated bytes.
+#[derive(Default, Debug)]
+pub struct CountingAllocator {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+    allocated: AtomicUsize,
+    allocations: AtomicUsize,
+}
+
+/// Only installed with the "alloc_stats" feature, it costs a few atomic
+/// operations per allocation.
+#[cfg_attr(feature = "alloc_stats", global_allocator)]
+static GLOBAL: CountingAllocator = CountingAllocator::new();
+
+/// Allocation during a span of time, from [`AllocSpan::finish`].
+#[derive(Serialize, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct AllocStats {
+    /// Highest live heap size above the size at the start of the span.
+    pub peak_bytes: usize,
+    /// Bytes requested by allocations, plus the growth of reallocations.
+    pub allocated_bytes: usize,
+    /// Reallocations resize an existing allocation and are not counted.
+    pub allocations: usize,
+}
+
+pub struct AllocSpan {
+    start_current: usize,
+    start_allocated: usize,
+    start_allocations: usize,
+}
+
+impl CountingAllocator {
+    pub const fn new() -> Self {
+        Self {
+            current: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+            allocated: AtomicUsize::new(0),
+            allocations: AtomicUsize::new(0),
+        }
+    }
+
+    /// Start measuring, peak is reset to the live heap size.
+    pub fn start(&self) -> AllocSpan {
+        let start_current = self.current.load(Ordering::Relaxed);
+        self.peak.store(start_current, Ordering::Relaxed);
+        AllocSpan {
+            start_current,
+            start_allocated: self.allocated.load(Ordering::Relaxed),
+            start_allocations: self.allocations.load(Ordering::Relaxed),
+        }
+    }
+
+    fn add(&self, size: usize) {
+        let current = self.current.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(current, Ordering::Relaxed);
+        self.allocated.fetch_add(size, Ordering::Relaxed);
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn remove(&self, size: usize) {
+        self.current.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    /// Only the size difference moves the counters, a grown buffer is not a
+    /// new allocation.
+    fn resize(&self, old_size: usize, new_size: usize) {
+        if new_size > old_size {
+            let growth = new_size - old_size;
+            let current = self.current.fetch_add(growth, Ordering::Relaxed) + growth;
+            self.peak.fetch_max(current, Ordering::Relaxed);
+            self.allocated.fetch_add(growth, Ordering::Relaxed);
+        } else {
+            self.remove(old_size - new_size);
+        }
+    }
+}
+
+impl AllocSpan {
+    pub fn finish(&self, allocator: &CountingAllocator) -> AllocStats {
+        AllocStats {
+            peak_bytes: allocator.peak.load(Ordering::Relaxed).saturating_sub(self.start_current),
+            allocated_bytes: allocator.allocated.load(Ordering::Relaxed) - self.start_allocated,
+            allocations: allocator.allocations.load(Ordering::Relaxed) - self.start_allocations,
+        }
+    }
+}
+
+/// Measure allocations of the global allocator, `None` unless built with the
+/// "alloc_stats" feature.
+pub fn start() -> Option<AllocSpan> {
+    cfg!(feature = "alloc_stats").then(|| GLOBAL.start())
+}
+
+pub fn finish(span: &AllocSpan) -> AllocStats {
+    span.finish(&GLOBAL)
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.remove(layout.size());
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.resize(layout.size(), new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Byte count displayed with binary unit, e.g. "1.50 MiB".
+pub struct Bytes(pub usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{:.2} {}", value, UNITS[unit])
+    }
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak {}, total {} in {} allocations",
+            Bytes(self.peak_bytes),
+            Bytes(self.allocated_bytes),
+            self.allocations
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::alloc::{GlobalAlloc, Layout};
+
+    use crate::alloc_stats::{AllocStats, Bytes, CountingAllocator};
+
+    #[test]
+    fn test_counting() {
+        let allocator = CountingAllocator::new();
+        let small = Layout::from_size_align(100, 8).unwrap();
+        let large = Layout::from_size_align(1000, 8).unwrap();
+        unsafe {
+            let kept = allocator.alloc(small);
+            let span = allocator.start();
+            let ptr = allocator.alloc(large);
+            allocator.dealloc(ptr, large);
+            let ptr = allocator.alloc(small);
+            let ptr = allocator.realloc(ptr, small, 300);
+            assert_eq!(
+                span.finish(&allocator),
+                AllocStats { peak_bytes: 1000, allocated_bytes: 1300, allocations: 2 }
+            );
+            let ptr = allocator.realloc(ptr, Layout::from_size_align(300, 8).unwrap(), 1500);
+            let ptr = allocator.realloc(ptr, Layout::from_size_align(1500, 8).unwrap(), 200);
+            assert_eq!(
+                span.finish(&allocator),
+                AllocStats { peak_bytes: 1500, allocated_bytes: 2500, allocations: 2 }
+            );
+            allocator.dealloc(ptr, Layout::from_size_align(200, 8).unwrap());
+            allocator.dealloc(kept, small);
+        }
+    }
+
+    #[test]
+    fn test_bytes_display() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.50 KiB");
+        assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.00 MiB");
+    }
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::client::AocClient;
+
+/// Stars earned per day, read from the `aria-label="Day 1, two stars"` links
+/// of the event calendar page.
+pub fn parse_calendar_stars(html: &str) -> HashMap<u8, u8> {
+    html.split("aria-label=\"Day ")
+        .skip(1)
+        .filter_map(|label| {
+            let label = label.split_once('"')?.0;
+            let (day, stars) = label.split_once(',').unwrap_or((label, ""));
+            let stars = match stars.trim() {
+                "one star" => 1,
+                "two stars" => 2,
+                _ => 0,
+            };
+            Some((day.trim().parse().ok()?, stars))
+        })
+        .collect()
+}
+
+pub fn get_calendar_stars(client: &AocClient, year: u16) -> Result<HashMap<u8, u8>> {
+    Ok(parse_calendar_stars(&client.get_text(&format!("/{}", year))?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use indoc::indoc;
+
+    use crate::client::calendar::parse_calendar_stars;
+
+    #[test]
+    fn test_parse_calendar_stars() {
+        let html = indoc! {r#"
+            <pre class="calendar">
+            <a aria-label="Day 1, two stars" href="/2023/day/1" class="calendar-day1 calendar-verycomplete">1</a>
+            <a aria-label="Day 2, one star" href="/2023/day/2" class="calendar-day2 calendar-complete">2</a>
+            <a aria-label="Day 3" href="/2023/day/3" class="calendar-day3">3</a>
+            </pre>
+        "#};
+        assert_eq!(parse_calendar_stars(html), HashMap::from([(1, 2), (2, 1), (3, 0)]));
+    }
+}
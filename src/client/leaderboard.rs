@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::fs::{create_dir_all, read_to_string};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::client::AocClient;
+use crate::input::unlock::{unlock_time, Clock, HhMmSs};
+
+/// Advent of code asks to not fetch a private leaderboard more often than
+/// this.
+pub const LEADERBOARD_REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Private leaderboard json, as served by
+/// "/{year}/leaderboard/private/view/{id}.json".
+#[derive(Deserialize, Debug)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    pub last_star_ts: u64,
+    /// Day, then part ("1" or "2"), to the star.
+    #[serde(default)]
+    pub completion_day_level: HashMap<String, HashMap<String, Star>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    fn star_ts(&self, day: u8, part: u8) -> Option<u64> {
+        self.completion_day_level
+            .get(&day.to_string())?
+            .get(&part.to_string())
+            .map(|star| star.get_star_ts)
+    }
+}
+
+impl Leaderboard {
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Members by local score, ties go to whoever got their last star first.
+    pub fn rankings(&self) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by(|l, r| {
+            r.local_score.cmp(&l.local_score).then(l.last_star_ts.cmp(&r.last_star_ts))
+        });
+        members
+    }
+
+    /// Rankings, then for each day every member's star times since the puzzle
+    /// unlocked and the delta between part 1 and part 2.
+    pub fn render(&self, year: u16, day: Option<u8>) -> Result<String> {
+        let rankings = self.rankings();
+        let name_width = rankings.iter().map(|m| m.display_name().len()).max().unwrap_or(0);
+        let mut out = String::new();
+        writeln!(out, "Private leaderboard {} of user #{}", self.event, self.owner_id)?;
+        for (rank, member) in rankings.iter().enumerate() {
+            writeln!(
+                out,
+                "{:>3}) {:>5} {:>3}* {}",
+                rank + 1,
+                member.local_score,
+                member.stars,
+                member.display_name()
+            )?;
+        }
+
+        let days = match day {
+            Some(day) => day..=day,
+            None => 1..=25,
+        };
+        for day in days {
+            let since_unlock = |ts: Option<u64>| -> Result<Option<Duration>> {
+                let unlock = unlock_time(year, day)?.duration_since(UNIX_EPOCH)?;
+                Ok(ts.map(|ts| Duration::from_secs(ts).saturating_sub(unlock)))
+            };
+            let mut lines = Vec::new();
+            for member in &rankings {
+                let part_1 = since_unlock(member.star_ts(day, 1))?;
+                let part_2 = since_unlock(member.star_ts(day, 2))?;
+                let Some(part_1) = part_1 else {
+                    continue;
+                };
+                let part_2 = match part_2 {
+                    Some(part_2) => format!(
+                        "{:>9}  (+{})",
+                        HhMmSs(part_2).to_string(),
+                        HhMmSs(part_2.saturating_sub(part_1))
+                    ),
+                    None => format!("{:>9}", "-"),
+                };
+                lines.push((
+                    part_1,
+                    format!(
+                        "  {:<name_width$}  {:>9}  {}",
+                        member.display_name(),
+                        HhMmSs(part_1).to_string(),
+                        part_2
+                    ),
+                ));
+            }
+            if lines.is_empty() {
+                continue;
+            }
+            lines.sort_by_key(|(part_1, _)| *part_1);
+            writeln!(out)?;
+            writeln!(out, "Day {}", day)?;
+            lines.into_iter().try_for_each(|(_, line)| writeln!(out, "{}", line.trim_end()))?;
+        }
+        Ok(out)
+    }
+}
+
+pub fn get_leaderboard_path(base_input_path: &Path, year: u16, id: u64) -> PathBuf {
+    base_input_path.join(format!("y{}/leaderboard_{}.json", year, id))
+}
+
+/// Leaderboard cached at [`get_leaderboard_path`], fetched again only once the
+/// cache is older than [`LEADERBOARD_REFRESH_INTERVAL`].
+pub fn get_leaderboard(
+    client: &AocClient,
+    clock: &dyn Clock,
+    base_input_path: &Path,
+    year: u16,
+    id: u64,
+) -> Result<Leaderboard> {
+    let path = get_leaderboard_path(base_input_path, year, id);
+    let fresh = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| clock.now().duration_since(modified).ok())
+        .is_some_and(|age| age < LEADERBOARD_REFRESH_INTERVAL);
+    if fresh {
+        let json = read_to_string(&path)
+            .with_context(|| format!("Failed to read leaderboard file {:?}", path))?;
+        return Leaderboard::from_json(&json)
+            .with_context(|| format!("Failed to parse leaderboard file {:?}", path));
+    }
+
+    let json = client.get_text(&format!("/{}/leaderboard/private/view/{}.json", year, id))?;
+    let leaderboard = Leaderboard::from_json(&json).context("Failed to parse leaderboard")?;
+    create_dir_all(
+        path.parent().with_context(|| format!("Failed to get parent for path {:?}", path))?,
+    )
+    .with_context(|| format!("Failed to create parent dir for path {:?}", path))?;
+    fs::write(&path, json)
+        .with_context(|| format!("Failed to write leaderboard file {:?}", path))?;
+    Ok(leaderboard)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::remove_dir_all;
+    use std::sync::Mutex;
+    use std::time::{Duration, SystemTime};
+
+    use anyhow::Result;
+    use indoc::indoc;
+
+    use crate::client::leaderboard::{get_leaderboard, Leaderboard};
+    use crate::client::session::Session;
+    use crate::client::stub_server::StubServer;
+    use crate::client::{AocClient, AocClientConfig};
+    use crate::input::unlock::tests::FakeClock;
+    use crate::input::unlock::Clock;
+
+    const LEADERBOARD: &str = indoc! {r#"
+        {
+          "owner_id": 42,
+          "event": "2023",
+          "members": {
+            "42": {
+              "id": 42,
+              "name": "khangp0000",
+              "stars": 2,
+              "local_score": 4,
+              "global_score": 0,
+              "last_star_ts": 1701407700,
+              "completion_day_level": {
+                "1": {
+                  "1": { "get_star_ts": 1701407100, "star_index": 1 },
+                  "2": { "get_star_ts": 1701407700, "star_index": 2 }
+                }
+              }
+            },
+            "7": {
+              "id": 7,
+              "name": null,
+              "stars": 1,
+              "local_score": 2,
+              "global_score": 0,
+              "last_star_ts": 1701406860,
+              "completion_day_level": {
+                "1": {
+                  "1": { "get_star_ts": 1701406860, "star_index": 3 }
+                }
+              }
+            }
+          }
+        }
+    "#};
+
+    #[test]
+    fn test_render() -> Result<()> {
+        let leaderboard = Leaderboard::from_json(LEADERBOARD)?;
+        assert_eq!(
+            leaderboard.render(2023, None)?,
+            indoc! {"
+                Private leaderboard 2023 of user #42
+                  1)     4   2* khangp0000
+                  2)     2   1* (anonymous user #7)
+
+                Day 1
+                  (anonymous user #7)   00:01:00          -
+                  khangp0000            00:05:00   00:15:00  (+00:10:00)
+            "}
+        );
+        assert!(!leaderboard.render(2023, Some(2))?.contains("Day"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_refresh_after_interval() -> Result<()> {
+        let base_input_path = temp_dir().join(format!("aoc_leaderboard_{}", std::process::id()));
+        let server = StubServer::start(vec![
+            (200, LEADERBOARD.to_owned()),
+            (200, LEADERBOARD.replace("\"local_score\": 4", "\"local_score\": 9")),
+        ]);
+        let client = AocClient::new(
+            Session::Value("abc123".to_owned()),
+            AocClientConfig {
+                base_url: server.base_url(),
+                min_request_interval: Duration::ZERO,
+                ..AocClientConfig::default()
+            },
+        )?;
+        let clock = FakeClock(Mutex::new(SystemTime::now()));
+
+        let leaderboard = get_leaderboard(&client, &clock, &base_input_path, 2023, 42)?;
+        assert_eq!(leaderboard.members["42"].local_score, 4);
+        clock.sleep(Duration::from_secs(60));
+        let leaderboard = get_leaderboard(&client, &clock, &base_input_path, 2023, 42)?;
+        assert_eq!(leaderboard.members["42"].local_score, 4);
+        assert_eq!(server.requests().len(), 1);
+
+        clock.sleep(Duration::from_secs(15 * 60));
+        let leaderboard = get_leaderboard(&client, &clock, &base_input_path, 2023, 42)?;
+        assert_eq!(leaderboard.members["42"].local_score, 9);
+        assert_eq!(server.requests()[1].path, "/2023/leaderboard/private/view/42.json");
+
+        remove_dir_all(base_input_path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,270 @@
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use reqwest::blocking::Client;
+use reqwest::StatusCode;
+use thiserror::Error;
+use tracing::{debug, warn};
+
+use crate::client::session::Session;
+
+pub mod calendar;
+pub mod leaderboard;
+pub mod puzzle;
+pub mod session;
+#[cfg(test)]
+pub mod stub_server;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_USER_AGENT: &str =
+    concat!("github.com/khangp0000/aoc_try_with_rust v", env!("CARGO_PKG_VERSION"));
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Session file not found: {0:?}, put your session cookie value in it")]
+    MissingSessionFile(PathBuf),
+    #[error("Session expired or invalid (got login page from {0}), please update session cookie")]
+    SessionExpired(String),
+    #[error("Puzzle is locked or does not exist: {0}")]
+    PuzzleLocked(String),
+    #[error("Request to {0} failed with status {1}")]
+    HttpStatus(String, StatusCode),
+}
+
+/// Settings of [`AocClient`].
+#[derive(Clone, Debug)]
+pub struct AocClientConfig {
+    pub base_url: String,
+    pub user_agent: String,
+    /// Minimum time between the start of two requests.
+    pub min_request_interval: Duration,
+    pub timeout: Duration,
+    /// How many time a request failed with 5xx status is retried.
+    pub max_retries: u32,
+    /// Wait time before first retry, doubled for each next retry.
+    pub retry_backoff: Duration,
+}
+
+impl Default for AocClientConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            min_request_interval: Duration::from_secs(3),
+            timeout: Duration::from_secs(30),
+            max_retries: 3,
+            retry_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Authenticated client for advent of code website.
+#[derive(Debug)]
+pub struct AocClient {
+    client: Client,
+    config: AocClientConfig,
+    session: Session,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl AocClient {
+    pub fn new(session: Session, config: AocClientConfig) -> Result<Self> {
+        let client = Client::builder()
+            .user_agent(&config.user_agent)
+            .timeout(config.timeout)
+            .build()
+            .context("Failed to build http client")?;
+        Ok(Self { client, config, session, last_request: Mutex::default() })
+    }
+
+    /// Wait so that requests are at least `min_request_interval` apart.
+    fn throttle(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+        if let Some(last) = *last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.config.min_request_interval {
+                debug!(wait = ?(self.config.min_request_interval - elapsed), "throttling request");
+                sleep(self.config.min_request_interval - elapsed);
+            }
+        }
+        *last_request = Some(Instant::now());
+    }
+
+    /// Authenticated GET of `path` (relative to base url, e.g.
+    /// "/2023/day/1/input"), returning response body.
+    pub fn get_text(&self, path: &str) -> Result<String> {
+        let session = self.session.read()?;
+        let url = format!("{}{}", self.config.base_url, path);
+        let mut attempt = 0;
+        loop {
+            self.throttle();
+            debug!(url, attempt, "sending request");
+            let response = self
+                .client
+                .get(&url)
+                .header("cookie", format!("session={}", session))
+                .send()
+                .with_context(|| format!("Failed to send get request to {}", url))?;
+            let status = response.status();
+            if status.is_success() {
+                return response
+                    .text()
+                    .with_context(|| format!("Failed to read response body from {}", url));
+            }
+
+            let body = response.text().unwrap_or_default();
+            if (status == StatusCode::BAD_REQUEST || status == StatusCode::INTERNAL_SERVER_ERROR)
+                && is_login_page(&body)
+            {
+                return Err(Error::SessionExpired(url).into());
+            }
+            if status == StatusCode::NOT_FOUND {
+                return Err(Error::PuzzleLocked(url).into());
+            }
+            if status.is_server_error() && attempt < self.config.max_retries {
+                warn!(url, %status, attempt, "server error, retrying");
+                sleep(self.config.retry_backoff * 2_u32.pow(attempt));
+                attempt += 1;
+                continue;
+            }
+            return Err(Error::HttpStatus(url, status).into());
+        }
+    }
+
+    /// Name of the user owning the session, as shown in the page header.
+    pub fn whoami(&self) -> Result<String> {
+        let url = format!("{}/", self.config.base_url);
+        let page = self.get_text("/")?;
+        Ok(parse_user_name(&page).ok_or(Error::SessionExpired(url))?)
+    }
+}
+
+fn parse_user_name(page: &str) -> Option<String> {
+    static USER_REGEX: OnceLock<Regex> = OnceLock::new();
+    USER_REGEX
+        .get_or_init(|| Regex::new(r#"<div class="user">([^<]+)"#).unwrap())
+        .captures(page)
+        .map(|c| c[1].trim().to_owned())
+        .filter(|name| !name.is_empty())
+}
+
+fn is_login_page(body: &str) -> bool {
+    let body = body.to_lowercase();
+    body.contains("log in") || body.contains("/auth/login")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::time::{Duration, Instant};
+
+    use anyhow::Result;
+
+    use crate::client::session::Session;
+    use crate::client::stub_server::StubServer;
+    use crate::client::{AocClient, AocClientConfig, Error};
+
+    fn client(server: &StubServer) -> Result<AocClient> {
+        AocClient::new(
+            Session::Value("abc123\n".to_owned()),
+            AocClientConfig {
+                base_url: server.base_url(),
+                user_agent: "test-agent".to_owned(),
+                min_request_interval: Duration::ZERO,
+                timeout: Duration::from_secs(5),
+                max_retries: 2,
+                retry_backoff: Duration::from_millis(1),
+            },
+        )
+    }
+
+    #[test]
+    fn test_get_sends_session_and_user_agent() -> Result<()> {
+        let server = StubServer::start(vec![(200, "1abc2".to_owned())]);
+        assert_eq!(client(&server)?.get_text("/2023/day/1/input")?, "1abc2");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/2023/day/1/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].header("user-agent"), Some("test-agent"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_retry_server_error() -> Result<()> {
+        let server = StubServer::start(vec![
+            (502, "Bad Gateway".to_owned()),
+            (503, "Unavailable".to_owned()),
+            (200, "ok".to_owned()),
+        ]);
+        assert_eq!(client(&server)?.get_text("/")?, "ok");
+        assert_eq!(server.requests().len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_classification() -> Result<()> {
+        let server = StubServer::start(vec![
+            (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.".into()),
+            (404, "Please don't repeatedly request this endpoint before it unlocks!".to_owned()),
+            (500, "Internal Server Error".to_owned()),
+            (500, "Internal Server Error".to_owned()),
+            (500, "Internal Server Error".to_owned()),
+        ]);
+        let client = client(&server)?;
+        let err = client.get_text("/").unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(Error::SessionExpired(_))));
+        let err = client.get_text("/").unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(Error::PuzzleLocked(_))));
+        let err = client.get_text("/").unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(Error::HttpStatus(_, _))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_session_file() -> Result<()> {
+        let server = StubServer::start(vec![]);
+        let client = AocClient::new(
+            Session::File(temp_dir().join("aoc_session_missing")),
+            AocClientConfig { base_url: server.base_url(), ..AocClientConfig::default() },
+        )?;
+        let err = client.get_text("/").unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(Error::MissingSessionFile(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_throttle() -> Result<()> {
+        let server = StubServer::start(vec![(200, String::new()), (200, String::new())]);
+        let client = AocClient::new(
+            Session::Value("abc123".to_owned()),
+            AocClientConfig {
+                base_url: server.base_url(),
+                min_request_interval: Duration::from_millis(200),
+                ..AocClientConfig::default()
+            },
+        )?;
+        let start = Instant::now();
+        client.get_text("/")?;
+        client.get_text("/")?;
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        Ok(())
+    }
+
+    #[test]
+    fn test_whoami() -> Result<()> {
+        let logged_in = r#"<div class="user">khangp0000 <span class="star-count">50*</span></div>"#;
+        let logged_out = r#"<div><a href="/2023/auth/login">[Log In]</a></div>"#;
+        let server =
+            StubServer::start(vec![(200, logged_in.to_owned()), (200, logged_out.to_owned())]);
+        let client = client(&server)?;
+        assert_eq!(client.whoami()?, "khangp0000");
+        let err = client.whoami().unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(Error::SessionExpired(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,281 @@
+use std::fs;
+use std::fs::{create_dir_all, read_to_string};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::client::AocClient;
+
+/// Puzzle page converted to markdown, with `<pre><code>` blocks kept aside as
+/// candidate sample inputs.
+#[derive(Debug, Eq, PartialEq)]
+pub struct PuzzleDescription {
+    pub markdown: String,
+    pub samples: Vec<String>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Split html into tags and text, comments and doctype are dropped.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.split_once("-->").map_or("", |(_, r)| r);
+        } else if let Some(tag) = rest.strip_prefix('<') {
+            let (tag, r) = tag.split_once('>').unwrap_or((tag, ""));
+            rest = r;
+            let tag = tag.trim_end_matches('/');
+            if let Some(name) = tag.strip_prefix('/') {
+                tokens.push(Token::Close(name.trim()));
+            } else if !tag.starts_with('!') {
+                let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                tokens.push(Token::Open(name, attrs));
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+fn attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{}=\"", name))? + name.len() + 2;
+    attrs[start..].split_once('"').map(|(v, _)| v)
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+impl PuzzleDescription {
+    /// Convert every `<article>` of a puzzle page, so part 2 is included when
+    /// page is fetched after solving part 1.
+    pub fn from_html(html: &str) -> Self {
+        let mut markdown = String::new();
+        let mut samples = Vec::new();
+        let mut article_depth = 0_usize;
+        let mut pre: Option<String> = None;
+        let mut in_code = false;
+        let mut hrefs = Vec::new();
+
+        for token in tokenize(html) {
+            match token {
+                Token::Open("article", _) => article_depth += 1,
+                Token::Close("article") => {
+                    article_depth = article_depth.saturating_sub(1);
+                }
+                _ if article_depth == 0 => {}
+                Token::Open("pre", _) => pre = Some(String::new()),
+                Token::Close("pre") => {
+                    if let Some(sample) = pre.take() {
+                        markdown.push_str("```\n");
+                        markdown.push_str(&sample);
+                        if !sample.ends_with('\n') {
+                            markdown.push('\n');
+                        }
+                        markdown.push_str("```\n\n");
+                        samples.push(sample);
+                    }
+                }
+                Token::Text(text) if pre.is_some() => {
+                    pre.as_mut().unwrap().push_str(&decode_entities(text))
+                }
+                _ if pre.is_some() => {}
+                Token::Open("h2", _) => markdown.push_str("## "),
+                Token::Close("h2") | Token::Close("p") | Token::Close("ul") => {
+                    markdown.truncate(markdown.trim_end().len());
+                    markdown.push_str("\n\n");
+                }
+                Token::Open("li", _) => markdown.push_str("- "),
+                Token::Close("li") => {
+                    markdown.truncate(markdown.trim_end().len());
+                    markdown.push('\n');
+                }
+                Token::Open("code", _) => {
+                    in_code = true;
+                    markdown.push('`');
+                }
+                Token::Close("code") => {
+                    in_code = false;
+                    markdown.push('`');
+                }
+                Token::Open("em", _) | Token::Close("em") if !in_code => markdown.push('*'),
+                Token::Open("a", attrs) => {
+                    hrefs.push(attr(attrs, "href").unwrap_or_default().to_owned());
+                    markdown.push('[');
+                }
+                Token::Close("a") => {
+                    markdown.push_str(&format!("]({})", hrefs.pop().unwrap_or_default()));
+                }
+                Token::Text(text) => {
+                    let text = decode_entities(text);
+                    let mut words = text.split_whitespace().peekable();
+                    let at_block_start = markdown.is_empty()
+                        || markdown.ends_with('\n')
+                        || markdown.ends_with("# ")
+                        || markdown.ends_with("- ");
+                    if text.starts_with(char::is_whitespace)
+                        && words.peek().is_some()
+                        && !at_block_start
+                    {
+                        markdown.push(' ');
+                    }
+                    markdown.push_str(&words.collect::<Vec<_>>().join(" "));
+                    if text.ends_with(char::is_whitespace) && !text.trim().is_empty() {
+                        markdown.push(' ');
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        markdown.truncate(markdown.trim_end().len());
+        markdown.push('\n');
+        Self { markdown, samples }
+    }
+
+    /// Read back markdown produced by [`PuzzleDescription::from_html`], samples
+    /// are taken from code fences.
+    pub fn from_markdown(markdown: String) -> Self {
+        let samples = markdown.split("```\n").skip(1).step_by(2).map(str::to_owned).collect();
+        Self { markdown, samples }
+    }
+
+    pub fn has_part_two(&self) -> bool {
+        self.markdown.contains("--- Part Two ---")
+    }
+
+    /// Render a sample the way day test modules declare them.
+    pub fn sample_as_test_const(sample: &str) -> String {
+        let mut out = String::from("const SAMPLE_INPUT: &str = indoc! {\"\n");
+        for line in sample.lines() {
+            out.push_str(&format!("        {}\n", line.replace('\\', "\\\\").replace('"', "\\\"")));
+        }
+        out.push_str("\"};\n");
+        out
+    }
+}
+
+pub fn get_description_path(base_input_path: &Path, year: u16, day: u8) -> PathBuf {
+    base_input_path.join(format!("y{}/day{}.md", year, day))
+}
+
+/// Cached description from "{base_input_path}/y{year}/day{day}.md", fetched
+/// again if part 2 is not in cache yet.
+pub fn get_description(
+    client: &AocClient,
+    base_input_path: &Path,
+    year: u16,
+    day: u8,
+) -> Result<PuzzleDescription> {
+    let path = get_description_path(base_input_path, year, day);
+    if path.is_file() {
+        let markdown = read_to_string(&path)
+            .with_context(|| format!("Failed to read description file {:?}", path))?;
+        let cached = PuzzleDescription::from_markdown(markdown);
+        if cached.has_part_two() {
+            return Ok(cached);
+        }
+    }
+
+    let html = client.get_text(&format!("/{}/day/{}", year, day))?;
+    let description = PuzzleDescription::from_html(&html);
+    create_dir_all(
+        path.parent().with_context(|| format!("Failed to get parent for path {:?}", path))?,
+    )
+    .with_context(|| format!("Failed to create parent dir for path {:?}", path))?;
+    fs::write(&path, &description.markdown)
+        .with_context(|| format!("Failed to write description file {:?}", path))?;
+    Ok(description)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::client::puzzle::PuzzleDescription;
+
+    const PAGE: &str = indoc! {r#"
+        <!DOCTYPE html>
+        <html lang="en-us">
+        <head><title>Day 1 - Advent of Code 2023</title></head>
+        <body>
+        <header><div class="user">khangp0000</div></header>
+        <main>
+        <article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is wrong with global snow production, and you've been selected to take a look.</p>
+        <p>For example:</p>
+        <pre><code>1abc2
+        pqr3stu8vwx
+        </code></pre>
+        <p>In this example, the calibration values of these lines are <code>12</code> and <code>38</code>. Adding these together produces <code><em>50</em></code>.</p>
+        <ul>
+        <li>See <a href="/2023/about">about</a> &amp; <em>more</em>.</li>
+        </ul>
+        </article>
+        <p>Your puzzle answer was <code>54331</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Your calculation isn't quite right.</p>
+        <pre><code>two1nine
+        </code></pre>
+        </article>
+        </main>
+        </body>
+        </html>
+    "#};
+
+    #[test]
+    fn test_from_html() {
+        let description = PuzzleDescription::from_html(PAGE);
+        assert_eq!(
+            description.markdown,
+            indoc! {"
+                ## --- Day 1: Trebuchet?! ---
+
+                Something is wrong with global snow production, and you've been selected to take a look.
+
+                For example:
+
+                ```
+                1abc2
+                pqr3stu8vwx
+                ```
+
+                In this example, the calibration values of these lines are `12` and `38`. Adding these together produces `50`.
+
+                - See [about](/2023/about) & *more*.
+
+                ## --- Part Two ---
+
+                Your calculation isn't quite right.
+
+                ```
+                two1nine
+                ```
+            "}
+        );
+        assert_eq!(description.samples, vec!["1abc2\npqr3stu8vwx\n", "two1nine\n"]);
+        assert!(description.has_part_two());
+        assert_eq!(PuzzleDescription::from_markdown(description.markdown.clone()), description);
+    }
+
+    #[test]
+    fn test_sample_as_test_const() {
+        assert_eq!(
+            PuzzleDescription::sample_as_test_const("1abc2\npqr3stu8vwx\n"),
+            "const SAMPLE_INPUT: &str = indoc! {\"\n        1abc2\n        pqr3stu8vwx\n\"};\n"
+        );
+    }
+}
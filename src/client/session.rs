@@ -0,0 +1,147 @@
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use thiserror::Error;
+
+use crate::client::Error::MissingSessionFile;
+
+/// Session cookie value from this environment variable is used instead of
+/// session file.
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// Prefix of the session environment variable of a named profile, followed by
+/// the upper cased profile name with '-' replaced by '_'. Kept apart from
+/// "AOC_{FLAG}" variables, e.g. profile "file" must not read "AOC_SESSION_FILE".
+pub const PROFILE_SESSION_ENV_VAR_PREFIX: &str = "AOC_SESSION_PROFILE_";
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Invalid profile name {0:?}, only ascii letters, digits, '-' and '_' are allowed")]
+    InvalidProfile(String),
+}
+
+/// Where the session cookie comes from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Session {
+    Value(String),
+    File(PathBuf),
+}
+
+/// Account used to talk to advent of code. Default profile uses given session
+/// file and input folder, named profile uses
+/// "{input_folder}/{profile}/session.txt" and stores inputs under
+/// "{input_folder}/{profile}".
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Profile(Option<String>);
+
+impl Profile {
+    pub fn new(name: Option<String>) -> Result<Self> {
+        if let Some(name) = &name {
+            if name.is_empty()
+                || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                Err(Error::InvalidProfile(name.clone()))?
+            }
+        }
+        Ok(Self(name))
+    }
+
+    pub fn input_folder(&self, input_folder: &Path) -> PathBuf {
+        match &self.0 {
+            None => input_folder.to_path_buf(),
+            Some(name) => input_folder.join(name),
+        }
+    }
+
+    pub fn session_env_var(&self) -> String {
+        match &self.0 {
+            None => SESSION_ENV_VAR.to_owned(),
+            Some(name) => format!(
+                "{}{}",
+                PROFILE_SESSION_ENV_VAR_PREFIX,
+                name.to_ascii_uppercase().replace('-', "_")
+            ),
+        }
+    }
+
+    /// Environment variable wins over session file.
+    pub fn session<E: Fn(&str) -> Option<String>>(
+        &self,
+        session_file: &Path,
+        input_folder: &Path,
+        env: E,
+    ) -> Session {
+        if let Some(value) = env(&self.session_env_var()).filter(|v| !v.trim().is_empty()) {
+            return Session::Value(value);
+        }
+        match &self.0 {
+            None => Session::File(session_file.to_path_buf()),
+            Some(_) => Session::File(self.input_folder(input_folder).join("session.txt")),
+        }
+    }
+}
+
+impl Session {
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Session::Value(value) => Ok(value.trim().to_owned()),
+            Session::File(path) => match read_to_string(path) {
+                Ok(session) => Ok(session.trim().to_owned()),
+                Err(e) if e.kind() == ErrorKind::NotFound => Err(MissingSessionFile(path.clone()))?,
+                Err(e) => {
+                    Err(e).with_context(|| format!("Failed to read session file: {:?}", path))
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use anyhow::Result;
+
+    use crate::client::session::{Profile, Session};
+
+    #[test]
+    fn test_default_profile() -> Result<()> {
+        let profile = Profile::new(None)?;
+        let session = profile.session(Path::new("data/session.txt"), Path::new("data"), |_| None);
+        assert_eq!(session, Session::File(PathBuf::from("data/session.txt")));
+        assert_eq!(profile.input_folder(Path::new("data")), PathBuf::from("data"));
+
+        let session = profile.session(Path::new("data/session.txt"), Path::new("data"), |k| {
+            (k == "AOC_SESSION").then(|| "abc".to_owned())
+        });
+        assert_eq!(session, Session::Value("abc".to_owned()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_profile() -> Result<()> {
+        let profile = Profile::new(Some("alice".to_owned()))?;
+        let session = profile.session(Path::new("data/session.txt"), Path::new("data"), |_| None);
+        assert_eq!(session, Session::File(PathBuf::from("data/alice/session.txt")));
+        assert_eq!(profile.input_folder(Path::new("data")), PathBuf::from("data/alice"));
+
+        let session = profile.session(Path::new("data/session.txt"), Path::new("data"), |k| {
+            (k == "AOC_SESSION_PROFILE_ALICE").then(|| "abc".to_owned())
+        });
+        assert_eq!(session, Session::Value("abc".to_owned()));
+
+        assert!(Profile::new(Some("../bob".to_owned())).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_profile_session_env_var() -> Result<()> {
+        let env_var = |name: &str| Profile::new(Some(name.to_owned())).map(|p| p.session_env_var());
+        assert_eq!(env_var("file")?, "AOC_SESSION_PROFILE_FILE");
+        assert_eq!(env_var("work-alt")?, "AOC_SESSION_PROFILE_WORK_ALT");
+        assert_eq!(Profile::new(None)?.session_env_var(), "AOC_SESSION");
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::client::DEFAULT_USER_AGENT;
+
+pub const DEFAULT_CONFIG_PATH: &str = "aoc.toml";
+/// Environment variables overriding config file are "{ENV_PREFIX}{FIELD}", e.g.
+/// "AOC_INPUT_FOLDER".
+pub const ENV_PREFIX: &str = "AOC_";
+/// Solver parameters from env are "AOC_PARAM_Y{year}_DAY{day}_{NAME}".
+pub const PARAM_ENV_PREFIX: &str = "AOC_PARAM_";
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Invalid value {1:?} for {0}")]
+    InvalidValue(String, String),
+    #[error("Invalid solver parameter key {0:?}, expected \"y{{year}}.day{{day}}.{{name}}\"")]
+    InvalidParamKey(String),
+}
+
+#[derive(ValueEnum, Deserialize, Copy, Clone, Default, Eq, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// Key of a solver parameter: year, day and name.
+pub type ParamKey = (u16, u8, String);
+
+/// One layer of settings, layers are combined with [`Settings::or`] so CLI
+/// flags win over env vars, which win over config file.
+#[derive(Deserialize, Clone, Default, PartialEq, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    pub session_file: Option<PathBuf>,
+    pub profile: Option<String>,
+    pub input_folder: Option<PathBuf>,
+    pub shared_input_folder: Option<PathBuf>,
+    pub input_key_file: Option<PathBuf>,
+    pub year: Option<u16>,
+    pub threads: Option<usize>,
+    pub output_format: Option<OutputFormat>,
+    pub user_agent: Option<String>,
+    pub request_interval_ms: Option<u64>,
+    pub request_timeout_ms: Option<u64>,
+    pub max_retries: Option<u32>,
+    /// `[solver.y2023.day21]` tables in config file.
+    #[serde(rename = "solver")]
+    pub solver_tables: HashMap<String, HashMap<String, HashMap<String, toml::Value>>>,
+    #[serde(skip)]
+    pub solver_params: HashMap<ParamKey, String>,
+}
+
+impl Settings {
+    /// Load config file, missing file gives empty settings.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content =
+            read_to_string(path).with_context(|| format!("Failed to read config {:?}", path))?;
+        Self::from_toml(&content).with_context(|| format!("Failed to parse config {:?}", path))
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let mut settings: Settings = toml::from_str(content)?;
+        for (year, days) in settings.solver_tables.drain() {
+            for (day, params) in days {
+                for (name, value) in params {
+                    let value = match value {
+                        toml::Value::String(s) => s,
+                        value => value.to_string(),
+                    };
+                    let key = format!("{}.{}.{}", year, day, name);
+                    settings.solver_params.insert(parse_param_key(&key, '.')?, value);
+                }
+            }
+        }
+        Ok(settings)
+    }
+
+    pub fn from_env_vars<I: IntoIterator<Item = (String, String)>>(vars: I) -> Result<Self> {
+        let mut vars = vars
+            .into_iter()
+            .filter_map(|(k, v)| k.strip_prefix(ENV_PREFIX).map(|k| (k.to_owned(), v)))
+            .collect::<HashMap<_, _>>();
+        let solver_params = vars
+            .iter()
+            .filter_map(|(k, v)| {
+                k.strip_prefix(&PARAM_ENV_PREFIX[ENV_PREFIX.len()..]).map(|k| (k, v))
+            })
+            .map(|(k, v)| Ok((parse_param_key(&k.to_lowercase(), '_')?, v.clone())))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            session_file: vars.remove("SESSION_FILE").map(PathBuf::from),
+            profile: vars.remove("PROFILE"),
+            input_folder: vars.remove("INPUT_FOLDER").map(PathBuf::from),
+            shared_input_folder: vars.remove("SHARED_INPUT_FOLDER").map(PathBuf::from),
+            input_key_file: vars.remove("INPUT_KEY_FILE").map(PathBuf::from),
+            year: parse_env(&mut vars, "YEAR")?,
+            threads: parse_env(&mut vars, "THREADS")?,
+            output_format: vars
+                .remove("OUTPUT_FORMAT")
+                .map(|v| {
+                    OutputFormat::from_str(&v, true)
+                        .map_err(|_| Error::InvalidValue(format!("{}OUTPUT_FORMAT", ENV_PREFIX), v))
+                })
+                .transpose()?,
+            user_agent: vars.remove("USER_AGENT"),
+            request_interval_ms: parse_env(&mut vars, "REQUEST_INTERVAL_MS")?,
+            request_timeout_ms: parse_env(&mut vars, "REQUEST_TIMEOUT_MS")?,
+            max_retries: parse_env(&mut vars, "MAX_RETRIES")?,
+            solver_tables: HashMap::default(),
+            solver_params,
+        })
+    }
+
+    /// Fill every missing value of `self` from `lower`.
+    pub fn or(self, lower: Settings) -> Settings {
+        let mut solver_params = lower.solver_params;
+        solver_params.extend(self.solver_params);
+        Settings {
+            session_file: self.session_file.or(lower.session_file),
+            profile: self.profile.or(lower.profile),
+            input_folder: self.input_folder.or(lower.input_folder),
+            shared_input_folder: self.shared_input_folder.or(lower.shared_input_folder),
+            input_key_file: self.input_key_file.or(lower.input_key_file),
+            year: self.year.or(lower.year),
+            threads: self.threads.or(lower.threads),
+            output_format: self.output_format.or(lower.output_format),
+            user_agent: self.user_agent.or(lower.user_agent),
+            request_interval_ms: self.request_interval_ms.or(lower.request_interval_ms),
+            request_timeout_ms: self.request_timeout_ms.or(lower.request_timeout_ms),
+            max_retries: self.max_retries.or(lower.max_retries),
+            solver_tables: HashMap::default(),
+            solver_params,
+        }
+    }
+
+    pub fn session_file(&self) -> PathBuf {
+        self.session_file.clone().unwrap_or_else(|| PathBuf::from("data/session.txt"))
+    }
+
+    pub fn input_folder(&self) -> PathBuf {
+        self.input_folder.clone().unwrap_or_else(|| PathBuf::from("data"))
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads.unwrap_or_else(num_cpus::get)
+    }
+
+    pub fn output_format(&self) -> OutputFormat {
+        self.output_format.unwrap_or_default()
+    }
+
+    pub fn user_agent(&self) -> String {
+        self.user_agent.clone().unwrap_or_else(|| DEFAULT_USER_AGENT.to_owned())
+    }
+
+    pub fn request_interval(&self) -> Duration {
+        Duration::from_millis(self.request_interval_ms.unwrap_or(3000))
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms.unwrap_or(30000))
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries.unwrap_or(3)
+    }
+}
+
+fn parse_env<T: FromStr>(vars: &mut HashMap<String, String>, name: &str) -> Result<Option<T>> {
+    vars.remove(name)
+        .map(|v| {
+            v.trim().parse().map_err(|_| Error::InvalidValue(format!("{}{}", ENV_PREFIX, name), v))
+        })
+        .transpose()
+        .map_err(anyhow::Error::from)
+}
+
+/// Parse "y{year}{separator}day{day}{separator}{name}".
+pub fn parse_param_key(key: &str, separator: char) -> Result<ParamKey> {
+    let invalid = || Error::InvalidParamKey(key.to_owned());
+    let mut split = key.splitn(3, separator);
+    let year = split.next().and_then(|y| y.strip_prefix('y')).and_then(|y| y.parse().ok());
+    let day = split.next().and_then(|d| d.strip_prefix("day")).and_then(|d| d.parse().ok());
+    let name = split.next().filter(|n| !n.is_empty());
+    match (year, day, name) {
+        (Some(year), Some(day), Some(name)) => Ok((year, day, name.to_owned())),
+        _ => Err(invalid())?,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use anyhow::Result;
+    use indoc::indoc;
+
+    use crate::config::{OutputFormat, Settings};
+
+    const CONFIG: &str = indoc! {r#"
+        input_folder = "shared/data"
+        year = 2023
+        threads = 2
+        output_format = "json"
+
+        [solver.y2023.day21]
+        steps = 26501365
+    "#};
+
+    #[test]
+    fn test_from_toml() -> Result<()> {
+        let settings = Settings::from_toml(CONFIG)?;
+        assert_eq!(settings.input_folder, Some(PathBuf::from("shared/data")));
+        assert_eq!(settings.year, Some(2023));
+        assert_eq!(settings.output_format(), OutputFormat::Json);
+        assert_eq!(settings.solver_params[&(2023, 21, "steps".to_owned())], "26501365");
+        assert!(Settings::from_toml("unknown = 1").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_layering() -> Result<()> {
+        let cli = Settings { threads: Some(8), ..Settings::default() };
+        let env = Settings::from_env_vars([
+            ("AOC_THREADS".to_owned(), "4".to_owned()),
+            ("AOC_YEAR".to_owned(), "2021".to_owned()),
+            ("AOC_PARAM_Y2023_DAY21_STEPS".to_owned(), "64".to_owned()),
+            ("HOME".to_owned(), "/root".to_owned()),
+        ])?;
+        let settings = cli.or(env).or(Settings::from_toml(CONFIG)?);
+
+        assert_eq!(settings.threads(), 8);
+        assert_eq!(settings.year, Some(2021));
+        assert_eq!(settings.input_folder(), PathBuf::from("shared/data"));
+        assert_eq!(settings.session_file(), PathBuf::from("data/session.txt"));
+        assert_eq!(settings.solver_params[&(2023, 21, "steps".to_owned())], "64");
+        assert!(Settings::from_env_vars([("AOC_YEAR".to_owned(), "x".to_owned())]).is_err());
+        Ok(())
+    }
+}
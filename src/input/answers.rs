@@ -0,0 +1,37 @@
+use std::fs;
+use std::fs::{create_dir_all, read_to_string};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// Known good answer of a day, as displayed by its solver, stored at
+/// "{base_input_path}/y{year}/day{day}.answer".
+pub fn get_answer_path(base_input_path: &Path, year: u16, day: u8) -> PathBuf {
+    base_input_path.join(format!("y{}/day{}.answer", year, day))
+}
+
+pub fn read_answer(base_input_path: &Path, year: u16, day: u8) -> Result<Option<String>> {
+    let path = get_answer_path(base_input_path, year, day);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let answer =
+        read_to_string(&path).with_context(|| format!("Failed to read answer file {:?}", path))?;
+    Ok(Some(answer.trim_end().to_owned()))
+}
+
+pub fn record_answer(base_input_path: &Path, year: u16, day: u8, answer: &str) -> Result<()> {
+    let path = get_answer_path(base_input_path, year, day);
+    create_dir_all(
+        path.parent().with_context(|| format!("Failed to get parent for path {:?}", path))?,
+    )
+    .with_context(|| format!("Failed to create parent dir for path {:?}", path))?;
+    fs::write(&path, format!("{}\n", answer))
+        .with_context(|| format!("Failed to write answer file {:?}", path))
+}
+
+/// Solver results built on an unverified assumption contain
+/// "{result} --{warning}--", see [`crate::utils::WarningResult`].
+pub fn has_assumption(answer: &str) -> bool {
+    answer.split_once(" --").is_some_and(|(_, warning)| warning.contains("--"))
+}
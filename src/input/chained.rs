@@ -0,0 +1,60 @@
+use anyhow::Result;
+use derive_new::new;
+use tracing::debug;
+
+use crate::input::InputProvider;
+
+/// Ask each provider in order, the first one having the input wins. Input is
+/// then saved to every provider before it, so a folder placed before a
+/// downloading provider acts as a cache.
+#[derive(new)]
+pub struct ChainedInputProvider {
+    providers: Vec<Box<dyn InputProvider>>,
+}
+
+impl InputProvider for ChainedInputProvider {
+    fn get_input(&self, year: u16, day: u8) -> Result<Option<String>> {
+        for (idx, provider) in self.providers.iter().enumerate() {
+            if let Some(input) = provider.get_input(year, day)? {
+                debug!(provider = idx, bytes = input.len(), "input found");
+                self.providers[..idx]
+                    .iter()
+                    .try_for_each(|provider| provider.save_input(year, day, &input))?;
+                return Ok(Some(input));
+            }
+        }
+        Ok(None)
+    }
+
+    fn save_input(&self, year: u16, day: u8, input: &str) -> Result<()> {
+        self.providers.iter().try_for_each(|provider| provider.save_input(year, day, input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::input::chained::ChainedInputProvider;
+    use crate::input::memory::MemoryInputProvider;
+    use crate::input::InputProvider;
+
+    #[test]
+    fn test_first_provider_wins_and_is_cached() -> Result<()> {
+        let chained = ChainedInputProvider::new(vec![
+            Box::new(MemoryInputProvider::default()),
+            Box::new(MemoryInputProvider::default().with_input(2023, 1, "shared")),
+            Box::new(MemoryInputProvider::default().with_input(2023, 1, "remote")),
+        ]);
+        assert_eq!(chained.get_input(2023, 1)?.as_deref(), Some("shared"));
+        assert_eq!(chained.providers[0].get_input(2023, 1)?.as_deref(), Some("shared"));
+        assert_eq!(chained.providers[2].get_input(2023, 1)?.as_deref(), Some("remote"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_not_found() {
+        let chained = ChainedInputProvider::new(vec![Box::new(MemoryInputProvider::default())]);
+        assert!(chained.require_input(2023, 1).is_err());
+    }
+}
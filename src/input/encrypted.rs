@@ -0,0 +1,301 @@
+use std::fmt::{Debug, Formatter};
+use std::fs;
+use std::fs::{create_dir_all, read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use thiserror::Error;
+
+use crate::input::fs::get_input_path;
+
+/// Hex encoded input key, used instead of key file when set.
+pub const INPUT_KEY_ENV_VAR: &str = "AOC_INPUT_KEY";
+/// Encrypted input is stored at "{input_path}.enc".
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+const NONCE_LEN: usize = 24;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Invalid input key, expected 64 hex characters")]
+    InvalidKey,
+    #[error("Failed to decrypt {0:?}, wrong key or corrupted file")]
+    DecryptFailed(PathBuf),
+    #[error("Input {0:?} is encrypted, an input key is required to read it")]
+    KeyRequired(PathBuf),
+    #[error("Key file already exists: {0:?}")]
+    KeyFileExists(PathBuf),
+}
+
+/// Encrypt stored inputs so they can be committed, file content is
+/// "{24 bytes nonce}{xchacha20poly1305 ciphertext}".
+pub struct InputCipher(XChaCha20Poly1305);
+
+impl Debug for InputCipher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("InputCipher(..)")
+    }
+}
+
+pub fn get_encrypted_input_path(base_input_path: &Path, year: u16, day: u8) -> PathBuf {
+    let mut path = get_input_path(base_input_path, year, day).into_os_string();
+    path.push(".");
+    path.push(ENCRYPTED_EXTENSION);
+    path.into()
+}
+
+impl InputCipher {
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let hex = hex.trim();
+        // Checked byte by byte first so slicing below never splits a char.
+        if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            Err(Error::InvalidKey)?
+        }
+        let key = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| Error::InvalidKey))
+            .collect::<Result<Vec<u8>, _>>()?;
+        Ok(Self(XChaCha20Poly1305::new_from_slice(&key).map_err(|_| Error::InvalidKey)?))
+    }
+
+    pub fn from_key_file(path: &Path) -> Result<Self> {
+        let hex =
+            read_to_string(path).with_context(|| format!("Failed to read key file {:?}", path))?;
+        Self::from_hex(&hex).with_context(|| format!("Invalid key file {:?}", path))
+    }
+
+    /// Write a new random key to `path`, never overwrite an existing key.
+    pub fn generate_key_file(path: &Path) -> Result<Self> {
+        if path.exists() {
+            Err(Error::KeyFileExists(path.to_path_buf()))?
+        }
+        let key = XChaCha20Poly1305::generate_key(&mut OsRng);
+        let hex = key.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            create_dir_all(parent)
+                .with_context(|| format!("Failed to create parent dir for path {:?}", path))?;
+        }
+        fs::write(path, format!("{}\n", hex))
+            .with_context(|| format!("Failed to write key file {:?}", path))?;
+        Self::from_hex(&hex)
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> Result<Vec<u8>> {
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .0
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| anyhow::anyhow!("Failed to encrypt input"))?;
+        Ok(nonce.into_iter().chain(ciphertext).collect())
+    }
+
+    pub fn decrypt(&self, data: &[u8], path: &Path) -> Result<String> {
+        let decrypt_failed = || Error::DecryptFailed(path.to_path_buf());
+        if data.len() < NONCE_LEN {
+            Err(decrypt_failed())?
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let plaintext =
+            self.0.decrypt(XNonce::from_slice(nonce), ciphertext).map_err(|_| decrypt_failed())?;
+        Ok(String::from_utf8(plaintext).map_err(|_| decrypt_failed())?)
+    }
+
+    pub fn read_file(&self, path: &Path) -> Result<String> {
+        let data =
+            fs::read(path).with_context(|| format!("Failed to read input file {:?}", path))?;
+        self.decrypt(&data, path)
+    }
+}
+
+/// Every "{base_input_path}/y{year}/day{day}.txt.enc" file.
+pub fn encrypted_input_paths(base_input_path: &Path) -> Result<Vec<(u16, u8, PathBuf)>> {
+    input_paths(base_input_path, &format!(".txt.{}", ENCRYPTED_EXTENSION))
+}
+
+/// Every plain "{base_input_path}/y{year}/day{day}.txt" file.
+pub fn plain_input_paths(base_input_path: &Path) -> Result<Vec<(u16, u8, PathBuf)>> {
+    input_paths(base_input_path, ".txt")
+}
+
+fn input_paths(base_input_path: &Path, suffix: &str) -> Result<Vec<(u16, u8, PathBuf)>> {
+    let mut paths = Vec::new();
+    if !base_input_path.is_dir() {
+        return Ok(paths);
+    }
+    for year_entry in read_dir(base_input_path)
+        .with_context(|| format!("Failed to list input folder {:?}", base_input_path))?
+    {
+        let year_entry = year_entry?;
+        let Some(year) = year_entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.strip_prefix('y'))
+            .and_then(|year| year.parse::<u16>().ok())
+        else {
+            continue;
+        };
+        if !year_entry.path().is_dir() {
+            continue;
+        }
+        for day_entry in read_dir(year_entry.path())? {
+            let day_entry = day_entry?;
+            let day = day_entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix("day"))
+                .and_then(|name| name.strip_suffix(suffix))
+                .and_then(|day| day.parse::<u8>().ok());
+            if let Some(day) = day {
+                paths.push((year, day, day_entry.path()));
+            }
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Re-encrypt every stored input with `new_cipher`, all inputs are read
+/// before anything is written so a wrong old key changes nothing. Plain inputs
+/// are encrypted and removed, a plain input shadowed by an encrypted one is
+/// never read so it is only removed. Returns the number of re-encrypted and
+/// plain inputs.
+pub fn rekey(
+    base_input_path: &Path,
+    old_cipher: &InputCipher,
+    new_cipher: &InputCipher,
+) -> Result<(usize, usize)> {
+    let encrypted = encrypted_input_paths(base_input_path)?
+        .into_iter()
+        .map(|(_, _, path)| old_cipher.read_file(&path).map(|input| (path, input)))
+        .collect::<Result<Vec<_>>>()?;
+    let plain = plain_input_paths(base_input_path)?
+        .into_iter()
+        .map(|(year, day, path)| {
+            let encrypted_path = get_encrypted_input_path(base_input_path, year, day);
+            let input = if encrypted_path.is_file() {
+                None
+            } else {
+                Some(
+                    read_to_string(&path)
+                        .with_context(|| format!("Failed to read input file {:?}", path))?,
+                )
+            };
+            Ok((path, encrypted_path, input))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    for (path, input) in &encrypted {
+        write_encrypted(new_cipher, path, input)?;
+    }
+    for (path, encrypted_path, input) in &plain {
+        if let Some(input) = input {
+            write_encrypted(new_cipher, encrypted_path, input)?;
+        }
+        fs::remove_file(path).with_context(|| format!("Failed to remove input file {:?}", path))?;
+    }
+    Ok((encrypted.len(), plain.len()))
+}
+
+fn write_encrypted(cipher: &InputCipher, path: &Path, input: &str) -> Result<()> {
+    let mut tmp_path = path.to_path_buf().into_os_string();
+    tmp_path.push(".tmp");
+    fs::write(&tmp_path, cipher.encrypt(input)?)
+        .with_context(|| format!("Failed to write input file {:?}", tmp_path))?;
+    fs::rename(&tmp_path, path).with_context(|| format!("Failed to replace input file {:?}", path))
+}
+
+/// Decrypt every stored input to "{output_path}/y{year}/day{day}.txt", plain
+/// inputs without an encrypted copy are exported as is.
+pub fn export(base_input_path: &Path, cipher: &InputCipher, output_path: &Path) -> Result<usize> {
+    let encrypted = encrypted_input_paths(base_input_path)?;
+    let plain = plain_input_paths(base_input_path)?
+        .into_iter()
+        .filter(|(year, day, _)| !encrypted.iter().any(|(y, d, _)| y == year && d == day))
+        .collect::<Vec<_>>();
+    for (year, day, path) in encrypted.iter().chain(&plain) {
+        let target_path = get_input_path(output_path, *year, *day);
+        let input = match path.extension() {
+            Some(extension) if extension == ENCRYPTED_EXTENSION => cipher.read_file(path)?,
+            _ => read_to_string(path)
+                .with_context(|| format!("Failed to read input file {:?}", path))?,
+        };
+        create_dir_all(target_path.parent().unwrap())
+            .with_context(|| format!("Failed to create parent dir for path {:?}", target_path))?;
+        fs::write(&target_path, input)
+            .with_context(|| format!("Failed to write input file {:?}", target_path))?;
+    }
+    Ok(encrypted.len() + plain.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{read, read_to_string, remove_dir_all, remove_file, write};
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::input::encrypted::{export, get_encrypted_input_path, rekey, Error, InputCipher};
+    use crate::input::fs::FsInputProvider;
+    use crate::input::InputProvider;
+
+    const KEY_1: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const KEY_2: &str = "1f1e1d1c1b1a191817161514131211100f0e0d0c0b0a09080706050403020100";
+
+    #[test]
+    fn test_encrypt_decrypt() -> Result<()> {
+        let cipher = InputCipher::from_hex(KEY_1)?;
+        let data = cipher.encrypt("1abc2\n")?;
+        assert_eq!(cipher.decrypt(&data, Path::new("x"))?, "1abc2\n");
+        assert!(InputCipher::from_hex(KEY_2)?.decrypt(&data, Path::new("x")).is_err());
+        assert!(InputCipher::from_hex("abc").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_key_file() -> Result<()> {
+        let path = temp_dir().join(format!("aoc_bad_key_{}", std::process::id()));
+        // 64 bytes but only 63 chars, slicing it by byte pair would panic.
+        write(&path, format!("{}é\n", "a".repeat(62)))?;
+        let result = InputCipher::from_key_file(&path);
+        remove_file(&path)?;
+        let error = result.unwrap_err();
+        assert!(matches!(error.downcast_ref::<Error>(), Some(Error::InvalidKey)));
+        assert!(InputCipher::from_hex(&"g".repeat(64)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_store_rekey_export() -> Result<()> {
+        let base = temp_dir().join(format!("aoc_encrypted_{}", std::process::id()));
+        let provider = FsInputProvider::new(base.join("data"))
+            .with_cipher(Some(InputCipher::from_hex(KEY_1)?));
+        provider.save_input(2023, 1, "1abc2\n")?;
+        provider.save_input(2023, 2, "Game 1\n")?;
+
+        let encrypted_path = get_encrypted_input_path(&base.join("data"), 2023, 1);
+        assert!(!read(&encrypted_path)?.windows(5).any(|w| w == b"1abc2"));
+        assert_eq!(provider.get_input(2023, 1)?.as_deref(), Some("1abc2\n"));
+        assert!(FsInputProvider::new(base.join("data")).get_input(2023, 1).is_err());
+
+        let new_cipher = InputCipher::from_hex(KEY_2)?;
+        assert!(rekey(&base.join("data"), &new_cipher, &new_cipher).is_err());
+        // Plain inputs left over from before encryption was turned on.
+        write(base.join("data/y2023/day3.txt"), "...*\n")?;
+        write(base.join("data/y2023/day1.txt"), "stale\n")?;
+        let old_cipher = InputCipher::from_hex(KEY_1)?;
+        assert_eq!(export(&base.join("data"), &old_cipher, &base.join("plain"))?, 3);
+        assert_eq!(read_to_string(base.join("plain/y2023/day1.txt"))?, "1abc2\n");
+        assert_eq!(read_to_string(base.join("plain/y2023/day3.txt"))?, "...*\n");
+        assert_eq!(rekey(&base.join("data"), &old_cipher, &new_cipher)?, (2, 2));
+        assert!(!base.join("data/y2023/day1.txt").exists());
+        assert!(!base.join("data/y2023/day3.txt").exists());
+        assert_eq!(export(&base.join("data"), &new_cipher, &base.join("plain"))?, 3);
+        assert_eq!(read_to_string(base.join("plain/y2023/day2.txt"))?, "Game 1\n");
+        assert_eq!(read_to_string(base.join("plain/y2023/day3.txt"))?, "...*\n");
+
+        remove_dir_all(base)?;
+        Ok(())
+    }
+}
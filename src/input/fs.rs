@@ -0,0 +1,119 @@
+use std::fs;
+use std::fs::{create_dir_all, read_to_string};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use derive_new::new;
+
+use crate::input::encrypted::{get_encrypted_input_path, Error, InputCipher};
+use crate::input::InputProvider;
+
+/// Read input from "{base_input_path}/y{year}/day{day}.txt", inputs saved to
+/// this provider are written to the same path. With a cipher, inputs are
+/// saved encrypted to "{input_path}.enc" and plain inputs are still read.
+#[derive(new, Debug)]
+pub struct FsInputProvider {
+    base_input_path: PathBuf,
+    #[new(default)]
+    cipher: Option<InputCipher>,
+}
+
+pub fn get_input_path(base_input_path: &Path, year: u16, day: u8) -> PathBuf {
+    base_input_path.join(format!("y{}/day{}.txt", year, day))
+}
+
+impl FsInputProvider {
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        get_input_path(&self.base_input_path, year, day)
+    }
+
+    /// Whether input is stored, encrypted or not, without reading it.
+    pub fn has_input(&self, year: u16, day: u8) -> bool {
+        self.input_path(year, day).is_file()
+            || get_encrypted_input_path(&self.base_input_path, year, day).is_file()
+    }
+
+    pub fn with_cipher(mut self, cipher: Option<InputCipher>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+}
+
+impl InputProvider for FsInputProvider {
+    fn get_input(&self, year: u16, day: u8) -> Result<Option<String>> {
+        let encrypted_input_path = get_encrypted_input_path(&self.base_input_path, year, day);
+        if encrypted_input_path.is_file() {
+            return match &self.cipher {
+                Some(cipher) => cipher.read_file(&encrypted_input_path).map(Some),
+                None => Err(Error::KeyRequired(encrypted_input_path))?,
+            };
+        }
+        let input_path = self.input_path(year, day);
+        if !input_path.exists() {
+            return Ok(None);
+        }
+        if !input_path.is_file() {
+            anyhow::bail!(format!("Path is not a file: {:?}", input_path));
+        }
+
+        Ok(Some(
+            read_to_string(&input_path)
+                .with_context(|| format!("Failed to read input file {:?}", input_path))?,
+        ))
+    }
+
+    fn save_input(&self, year: u16, day: u8, input: &str) -> Result<()> {
+        let (target_path, content) = match &self.cipher {
+            Some(cipher) => {
+                (get_encrypted_input_path(&self.base_input_path, year, day), cipher.encrypt(input)?)
+            }
+            None => (self.input_path(year, day), input.as_bytes().to_vec()),
+        };
+        create_dir_all(
+            target_path
+                .parent()
+                .with_context(|| format!("Failed to get parent for path {:?}", target_path))?,
+        )
+        .with_context(|| format!("Failed to create parent dir for path {:?}", target_path))?;
+
+        match fs::write(&target_path, content) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                if target_path.exists() {
+                    fs::remove_file(&target_path).with_context(|| {
+                        format!(
+                            "Input file write failed but cannot delete for file path {:?}",
+                            target_path
+                        )
+                    })?;
+                }
+                Err(e).with_context(|| format!("Input file write failed {:?}", target_path))?
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::remove_dir_all;
+
+    use anyhow::Result;
+
+    use crate::input::fs::FsInputProvider;
+    use crate::input::InputProvider;
+
+    #[test]
+    fn test_save_then_get() -> Result<()> {
+        let base_input_path = temp_dir().join(format!("aoc_fs_input_{}", std::process::id()));
+        let provider = FsInputProvider::new(base_input_path.clone());
+        assert_eq!(provider.get_input(2023, 1)?, None);
+
+        provider.save_input(2023, 1, "1abc2\n")?;
+        assert!(base_input_path.join("y2023/day1.txt").is_file());
+        assert_eq!(provider.get_input(2023, 1)?.as_deref(), Some("1abc2\n"));
+
+        remove_dir_all(base_input_path)?;
+        Ok(())
+    }
+}
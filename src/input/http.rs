@@ -0,0 +1,67 @@
+use anyhow::Result;
+use derive_new::new;
+use tracing::info;
+
+use crate::client::AocClient;
+use crate::input::unlock::{ensure_unlocked, Clock};
+use crate::input::InputProvider;
+
+/// Download input from advent of code website. Puzzles that are not unlocked
+/// yet are refused, or waited for when `wait` is set.
+#[derive(new, Debug)]
+pub struct HttpInputProvider {
+    client: AocClient,
+    clock: Box<dyn Clock>,
+    wait: bool,
+}
+
+impl InputProvider for HttpInputProvider {
+    fn get_input(&self, year: u16, day: u8) -> Result<Option<String>> {
+        ensure_unlocked(self.clock.as_ref(), year, day, self.wait)?;
+        info!(year, day, "downloading input");
+        Ok(Some(self.client.get_text(&format!("/{}/day/{}/input", year, day))?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::Result;
+
+    use crate::client::session::Session;
+    use crate::client::stub_server::StubServer;
+    use crate::client::{AocClient, AocClientConfig};
+    use crate::input::http::HttpInputProvider;
+    use crate::input::unlock::tests::FakeClock;
+    use crate::input::InputProvider;
+
+    fn provider(server: &StubServer, wait: bool) -> Result<HttpInputProvider> {
+        let client = AocClient::new(
+            Session::Value("abc123".to_owned()),
+            AocClientConfig {
+                base_url: server.base_url(),
+                min_request_interval: Duration::ZERO,
+                ..AocClientConfig::default()
+            },
+        )?;
+        let clock = Box::new(FakeClock::at_unix(1701406800 - 60));
+        Ok(HttpInputProvider::new(client, clock, wait))
+    }
+
+    #[test]
+    fn test_refuse_before_unlock() -> Result<()> {
+        let server = StubServer::start(vec![(200, "input".to_owned())]);
+        assert!(provider(&server, false)?.get_input(2023, 1).is_err());
+        assert!(server.requests().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_wait_for_unlock() -> Result<()> {
+        let server = StubServer::start(vec![(200, "input".to_owned())]);
+        assert_eq!(provider(&server, true)?.get_input(2023, 1)?.as_deref(), Some("input"));
+        assert_eq!(server.requests()[0].path, "/2023/day/1/input");
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use anyhow::Result;
+
+use crate::input::InputProvider;
+
+/// Keep inputs in memory, mostly useful to supply inputs in tests.
+#[derive(Default, Debug)]
+pub struct MemoryInputProvider {
+    inputs: Mutex<HashMap<(u16, u8), String>>,
+}
+
+impl MemoryInputProvider {
+    pub fn with_input(self, year: u16, day: u8, input: impl Into<String>) -> Self {
+        self.inputs.lock().unwrap().insert((year, day), input.into());
+        self
+    }
+}
+
+impl InputProvider for MemoryInputProvider {
+    fn get_input(&self, year: u16, day: u8) -> Result<Option<String>> {
+        Ok(self.inputs.lock().unwrap().get(&(year, day)).cloned())
+    }
+
+    fn save_input(&self, year: u16, day: u8, input: &str) -> Result<()> {
+        self.inputs.lock().unwrap().insert((year, day), input.to_owned());
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+use thiserror::Error;
+
+pub mod answers;
+pub mod chained;
+pub mod encrypted;
+pub mod fs;
+pub mod http;
+#[cfg(test)]
+pub mod memory;
+pub mod unlock;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("No input provider has input for year {0} day {1}")]
+    InputNotFound(u16, u8),
+}
+
+/// Source of puzzle input, e.g. a local folder, the advent of code website or
+/// a map held in memory.
+pub trait InputProvider {
+    /// Get input for a specific year and day, `Ok(None)` means this provider
+    /// does not have it.
+    fn get_input(&self, year: u16, day: u8) -> Result<Option<String>>;
+
+    /// Store input that was provided by some other provider, used by
+    /// [`chained::ChainedInputProvider`] to cache inputs. Provider that cannot
+    /// store anything ignore it.
+    fn save_input(&self, _year: u16, _day: u8, _input: &str) -> Result<()> {
+        Ok(())
+    }
+
+    /// Same as [`InputProvider::get_input`] but fail if input is not found.
+    fn require_input(&self, year: u16, day: u8) -> Result<String> {
+        Ok(self.get_input(year, day)?.ok_or(Error::InputNotFound(year, day))?)
+    }
+}
+
+impl<P: InputProvider + ?Sized> InputProvider for Box<P> {
+    fn get_input(&self, year: u16, day: u8) -> Result<Option<String>> {
+        (**self).get_input(year, day)
+    }
+
+    fn save_input(&self, year: u16, day: u8, input: &str) -> Result<()> {
+        (**self).save_input(year, day, input)
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::Debug;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use thiserror::Error;
+use tracing::info;
+
+/// Puzzles unlock at midnight US Eastern (UTC-5, no daylight saving in
+/// December).
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("There is no puzzle for year {0} day {1}")]
+    InvalidPuzzle(u16, u8),
+    #[error("Puzzle for year {0} day {1} unlocks in {2}")]
+    Locked(u16, u8, HhMmSs),
+}
+
+/// Duration displayed as "HH:MM:SS", hours can go over 24.
+#[derive(Debug)]
+pub struct HhMmSs(pub Duration);
+
+impl std::fmt::Display for HhMmSs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let secs = self.0.as_secs() + u64::from(self.0.subsec_nanos() > 0);
+        write!(f, "{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    }
+}
+
+/// Source of current time, swapped with a fake one in tests.
+pub trait Clock: Debug {
+    fn now(&self) -> SystemTime;
+
+    fn sleep(&self, duration: Duration);
+}
+
+#[derive(Default, Debug)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration)
+    }
+}
+
+/// Days since 1970-01-01 of a proleptic gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+pub fn unlock_time(year: u16, day: u8) -> Result<SystemTime> {
+    if year < 2015 || !(1..=25).contains(&day) {
+        Err(Error::InvalidPuzzle(year, day))?
+    }
+    let days = days_from_civil(year.into(), 12, day.into()) as u64;
+    Ok(UNIX_EPOCH + Duration::from_secs(days * 86400 + UNLOCK_HOUR_UTC * 3600))
+}
+
+/// Time left until puzzle unlocks, `None` if it is already unlocked.
+pub fn time_until_unlock(clock: &dyn Clock, year: u16, day: u8) -> Result<Option<Duration>> {
+    Ok(unlock_time(year, day)?.duration_since(clock.now()).ok().filter(|d| !d.is_zero()))
+}
+
+/// Fail with [`Error::Locked`] if puzzle is not unlocked yet, or sleep until
+/// it is when `wait` is set.
+pub fn ensure_unlocked(clock: &dyn Clock, year: u16, day: u8, wait: bool) -> Result<()> {
+    if let Some(remaining) = time_until_unlock(clock, year, day)? {
+        if !wait {
+            Err(Error::Locked(year, day, HhMmSs(remaining)))?
+        }
+        info!(year, day, remaining = %HhMmSs(remaining), "waiting for puzzle to unlock");
+        clock.sleep(remaining);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::sync::Mutex;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use anyhow::Result;
+
+    use crate::input::unlock::{ensure_unlocked, unlock_time, Clock, Error, HhMmSs};
+
+    /// Clock that only moves when sleeping.
+    #[derive(Debug)]
+    pub struct FakeClock(pub Mutex<SystemTime>);
+
+    impl FakeClock {
+        pub fn at_unix(secs: u64) -> Self {
+            Self(Mutex::new(UNIX_EPOCH + Duration::from_secs(secs)))
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> SystemTime {
+            *self.0.lock().unwrap()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            *self.0.lock().unwrap() += duration;
+        }
+    }
+
+    #[test]
+    fn test_unlock_time() -> Result<()> {
+        assert_eq!(unlock_time(2023, 1)?, UNIX_EPOCH + Duration::from_secs(1701406800));
+        assert_eq!(unlock_time(2015, 25)?, UNIX_EPOCH + Duration::from_secs(1451019600));
+        assert!(unlock_time(2023, 26).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_locked_message() {
+        let clock = FakeClock::at_unix(1701406800 - 3723);
+        let err = ensure_unlocked(&clock, 2023, 1, false).unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(Error::Locked(2023, 1, _))));
+        assert_eq!(err.to_string(), "Puzzle for year 2023 day 1 unlocks in 01:02:03");
+        assert_eq!(HhMmSs(Duration::from_secs(100 * 3600)).to_string(), "100:00:00");
+    }
+
+    #[test]
+    fn test_wait() -> Result<()> {
+        let clock = FakeClock::at_unix(1701406800 - 10);
+        ensure_unlocked(&clock, 2023, 1, true)?;
+        assert_eq!(clock.now(), unlock_time(2023, 1)?);
+        ensure_unlocked(&clock, 2023, 1, false)
+    }
+}
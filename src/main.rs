@@ -1,33 +1,149 @@
-use std::path::PathBuf;
-use std::time::SystemTime;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
 use anyhow::bail;
-use anyhow::Result;
-use clap::Parser;
+use anyhow::{Context, Result};
+use clap::{ArgAction, Parser, Subcommand};
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::layer::{Layer, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::EnvFilter;
+use visualize::{new_sink, VisualizeFormat};
 
-use solver::AOC_PROBLEMS_SOLVER;
+use client::calendar::get_calendar_stars;
+use client::leaderboard::get_leaderboard;
+use client::puzzle::{get_description, PuzzleDescription};
+use client::session::Profile;
+use client::{AocClient, AocClientConfig};
+use config::{parse_param_key, OutputFormat, Settings, DEFAULT_CONFIG_PATH};
+use input::answers::{read_answer, record_answer};
+use input::chained::ChainedInputProvider;
+use input::encrypted::{export, rekey, InputCipher, INPUT_KEY_ENV_VAR};
+use input::fs::FsInputProvider;
+use input::http::HttpInputProvider;
+use input::unlock::{ensure_unlocked, SystemClock};
+use input::InputProvider;
+use solver::{get_day_visualizer, get_year_solver, set_solver_params, AOC_PROBLEMS_SOLVER};
+use status::{day_statuses, render_status};
 
+mod alloc_stats;
+mod client;
+mod config;
+mod input;
+mod serve;
 mod solver;
+mod status;
+#[cfg(feature = "tui")]
+mod tui;
 mod utils;
+mod visualize;
 
-/// Solve advent of code with command line.
+/// Solve advent of code with command line. Settings not given as flags are
+/// read from env vars "AOC_{FLAG}" (e.g. "AOC_INPUT_FOLDER"), then from config
+/// file.
 #[derive(Parser, Debug)]
-#[command(author, version, about, arg_required_else_help = true)]
+#[command(author, version, about)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Config file, env var "AOC_CONFIG" [default: aoc.toml].
+    #[arg(short, long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Log more to stderr: "-v" info, "-vv" debug, "-vvv" trace (includes
+    /// graph search stats). Env var "AOC_LOG" takes a filter instead, e.g.
+    /// "khangp0000_aoc_try_with_rust::utils::graph=trace".
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+
+    /// Write a chrome trace json of spans (input, parse, each part) to this
+    /// file, open it with "chrome://tracing" or "ui.perfetto.dev".
+    #[arg(long, global = true)]
+    chrome_trace: Option<PathBuf>,
+
     /// Path to session file, "cookie: session={session_file_content}" will be
-    /// used to get input data.
-    #[arg(short, long, default_value = "data/session.txt")]
-    session_file: PathBuf,
+    /// used to get input data. Ignored if env var "AOC_SESSION" is set
+    /// [default: data/session.txt].
+    #[arg(short, long, global = true)]
+    session_file: Option<PathBuf>,
+
+    /// Named profile, for teammates with separate accounts. Session is read
+    /// from env var "AOC_SESSION_PROFILE_{PROFILE}" ('-' becomes '_') or
+    /// "{input_folder}/{profile}/session.txt" and inputs are stored under
+    /// "{input_folder}/{profile}".
+    #[arg(short, long, global = true)]
+    profile: Option<String>,
 
     /// Input path folder, used to store downloaded input data. Will not
     /// re-download if file already exists. File path is
-    /// "{input_folder}/y{year}/day{day}.txt"
-    #[arg(short, long, default_value = "data")]
-    input_folder: PathBuf,
+    /// "{input_folder}/y{year}/day{day}.txt" [default: data].
+    #[arg(short, long, global = true)]
+    input_folder: Option<PathBuf>,
+
+    /// Shared input folder (e.g. a team cache), looked up after
+    /// `input_folder` and before downloading. Downloaded input are stored in
+    /// both folders.
+    #[arg(long, global = true)]
+    shared_input_folder: Option<PathBuf>,
+
+    /// Key file used to encrypt stored inputs so they can be committed, inputs
+    /// are then stored at "{input_path}.enc". Ignored if env var
+    /// "AOC_INPUT_KEY" is set.
+    #[arg(long, global = true)]
+    input_key_file: Option<PathBuf>,
+
+    /// User agent sent to advent of code, please include a way to contact you.
+    #[arg(long, global = true)]
+    user_agent: Option<String>,
+
+    /// Minimum interval between two requests to advent of code in
+    /// milliseconds [default: 3000].
+    #[arg(long, global = true)]
+    request_interval_ms: Option<u64>,
+
+    /// Timeout of a request to advent of code in milliseconds [default:
+    /// 30000].
+    #[arg(long, global = true)]
+    request_timeout_ms: Option<u64>,
+
+    /// How many time a request failing with server error is retried [default:
+    /// 3].
+    #[arg(long, global = true)]
+    max_retries: Option<u32>,
+
+    /// Wait for puzzles that are not unlocked yet then download and solve
+    /// them, instead of failing.
+    #[arg(long)]
+    wait: bool,
+
+    /// Record results as known good answers at
+    /// "{input_folder}/y{year}/day{day}.answer". Results differing from a
+    /// recorded answer are reported as errors.
+    #[arg(long)]
+    record_answers: bool,
+
+    /// Also emit intermediate states of days that support it (2023 days 10,
+    /// 14, 16, 21 and 22).
+    #[arg(long, value_name = "FORMAT")]
+    visualize: Option<VisualizeFormat>,
+
+    /// File for text frames or folder for images [default: stdout, or
+    /// "frames" for images].
+    #[arg(long)]
+    visualize_output: Option<PathBuf>,
+
+    /// Delay between frames of terminal animation in milliseconds.
+    #[arg(long, default_value_t = 100)]
+    frame_delay_ms: u64,
+
+    /// Pixels per cell side in images.
+    #[arg(long, default_value_t = 4)]
+    frame_scale: usize,
 
     /// Which year are you looking at.
     #[arg(short, long)]
-    year: u16,
+    year: Option<u16>,
 
     /// Which days are you looking at.
     #[arg(short, long, value_delimiter = ',')]
@@ -35,41 +151,391 @@ struct Args {
 
     /// How many thread to use (only apply to problem using multiple thread,
     /// problems are still solve sequentially, default to number of core).
-    #[arg(short, long, value_delimiter = ',')]
+    #[arg(short, long)]
     threads: Option<usize>,
+
+    /// Output format of results [default: text].
+    #[arg(short, long)]
+    output_format: Option<OutputFormat>,
+
+    /// Solver parameter as "y{year}.day{day}.{name}={value}", e.g.
+    /// "y2023.day21.steps=64".
+    #[arg(long = "param", value_name = "KEY=VALUE")]
+    params: Vec<String>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Show which advent of code user the session belongs to.
+    Whoami,
+
+    /// Show puzzle description as markdown, cached at
+    /// "{input_folder}/y{year}/day{day}.md" and fetched again until part 2 is
+    /// available.
+    Read {
+        /// Puzzle year.
+        #[arg(short, long)]
+        year: u16,
+
+        /// Puzzle day.
+        #[arg(short, long)]
+        day: u8,
+
+        /// Print code blocks of the description as sample input constants for
+        /// the day's test module instead.
+        #[arg(long)]
+        samples: bool,
+    },
+
+    /// Show a private leaderboard with per day star times, cached at
+    /// "{input_folder}/y{year}/leaderboard_{id}.json" and fetched again at
+    /// most every 15 minutes.
+    Leaderboard {
+        /// Leaderboard year.
+        #[arg(short, long)]
+        year: u16,
+
+        /// Only show star times of this day.
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Leaderboard id, the owner's user id.
+        id: u64,
+    },
+
+    /// Show a day grid of implemented solvers, cached inputs, recorded answers
+    /// and answers built on unverified assumptions.
+    Status {
+        /// Only show this year [default: every year with solvers].
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Also fetch stars earned from the event calendar.
+        #[arg(long)]
+        calendar: bool,
+    },
+
+    /// Interactive dashboard to run solvers and browse cached inputs, inputs
+    /// are never downloaded.
+    #[cfg(feature = "tui")]
+    Tui,
+
+    /// Serve solvers over a local http json api: "GET /solvers",
+    /// "GET /solvers/{year}" and "POST /solve/{year}/{day}[?part={part}]" with
+    /// input as body.
+    Serve {
+        /// Address to listen on.
+        #[arg(long, default_value = "127.0.0.1")]
+        bind: String,
+
+        /// Port to listen on.
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
+
+    /// Manage encrypted inputs of the input folder.
+    Store {
+        #[command(subcommand)]
+        command: StoreCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum StoreCommand {
+    /// Generate a new random input key file.
+    Keygen {
+        /// Where to write the key, existing file is never overwritten.
+        path: PathBuf,
+    },
+
+    /// Re-encrypt every stored input with a new key, current key is read from
+    /// "AOC_INPUT_KEY" or `--input-key-file`.
+    Rekey {
+        /// Key file holding the new key.
+        new_key_file: PathBuf,
+    },
+
+    /// Decrypt every stored input to "{output_folder}/y{year}/day{day}.txt".
+    Export {
+        /// Folder to write the plain inputs to.
+        output_folder: PathBuf,
+    },
+}
+
+impl Args {
+    fn settings(&self) -> Result<Settings> {
+        let solver_params = self
+            .params
+            .iter()
+            .map(|param| {
+                let (key, value) = param
+                    .split_once('=')
+                    .ok_or_else(|| anyhow::anyhow!("Invalid solver parameter {:?}", param))?;
+                Ok((parse_param_key(key, '.')?, value.to_owned()))
+            })
+            .collect::<Result<_>>()?;
+        let cli = Settings {
+            session_file: self.session_file.clone(),
+            profile: self.profile.clone(),
+            input_folder: self.input_folder.clone(),
+            shared_input_folder: self.shared_input_folder.clone(),
+            input_key_file: self.input_key_file.clone(),
+            year: self.year,
+            threads: self.threads,
+            output_format: self.output_format,
+            user_agent: self.user_agent.clone(),
+            request_interval_ms: self.request_interval_ms,
+            request_timeout_ms: self.request_timeout_ms,
+            max_retries: self.max_retries,
+            solver_params,
+            ..Settings::default()
+        };
+
+        let config_path = self
+            .config
+            .clone()
+            .or_else(|| std::env::var_os("AOC_CONFIG").map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_CONFIG_PATH));
+        Ok(cli
+            .or(Settings::from_env_vars(std::env::vars())?)
+            .or(Settings::from_file(&config_path)?))
+    }
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
-    rayon::ThreadPoolBuilder::default()
-        .num_threads(args.threads.unwrap_or_else(num_cpus::get))
-        .build_global()
-        .unwrap();
-    let solvers = AOC_PROBLEMS_SOLVER.get_entry(&args.year);
-    let (day_mapper_solvers, mut days) = match &solvers {
-        None => bail!(format!("There is no solver for selected year {}", args.year)),
-        Some(entry) => {
+    let _chrome_trace_guard = init_tracing(args.verbose, args.chrome_trace.as_deref())?;
+    let settings = args.settings()?;
+    match &args.command {
+        None => solve(&args, settings),
+        Some(Command::Whoami) => {
+            println!("{}", build_client(&settings)?.whoami()?);
+            Ok(())
+        }
+        Some(Command::Read { year, day, samples }) => read(&settings, *year, *day, *samples),
+        Some(Command::Leaderboard { year, day, id }) => {
+            let profile = Profile::new(settings.profile.clone())?;
+            let input_folder = profile.input_folder(&settings.input_folder());
+            let client = build_client(&settings)?;
+            let leaderboard = get_leaderboard(&client, &SystemClock, &input_folder, *year, *id)?;
+            print!("{}", leaderboard.render(*year, *day)?);
+            Ok(())
+        }
+        Some(Command::Status { year, calendar }) => status(&settings, *year, *calendar),
+        #[cfg(feature = "tui")]
+        Some(Command::Tui) => {
+            set_solver_params(settings.solver_params.clone());
+            tui::run(move || Ok(ChainedInputProvider::new(local_input_providers(&settings)?)))
+        }
+        Some(Command::Serve { bind, port }) => {
+            set_solver_params(settings.solver_params.clone());
+            serve::serve(bind, *port)
+        }
+        Some(Command::Store { command }) => store(&settings, command),
+    }
+}
+
+/// Logs go to stderr so results on stdout stay parsable, returned guard
+/// flushes chrome trace when dropped.
+fn init_tracing(verbose: u8, chrome_trace: Option<&Path>) -> Result<Option<FlushGuard>> {
+    let filter = match EnvFilter::try_from_env("AOC_LOG") {
+        Ok(filter) => filter,
+        Err(_) => EnvFilter::new(match verbose {
+            0 => "warn",
+            1 => "info",
+            2 => "debug",
+            _ => "trace",
+        }),
+    };
+    let (chrome_layer, guard) = match chrome_trace {
+        Some(path) => {
+            let (layer, guard) = ChromeLayerBuilder::new().file(path).include_args(true).build();
+            let crate_only = EnvFilter::new(format!("{}=trace", env!("CARGO_CRATE_NAME")));
+            (Some(layer.with_filter(crate_only)), Some(guard))
+        }
+        None => (None, None),
+    };
+    tracing_subscriber::registry()
+        .with(chrome_layer)
+        .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr).with_filter(filter))
+        .try_init()?;
+    Ok(guard)
+}
+
+fn status(settings: &Settings, year: Option<u16>, calendar: bool) -> Result<()> {
+    let profile = Profile::new(settings.profile.clone())?;
+    let input_folder = profile.input_folder(&settings.input_folder());
+    let mut input_providers = vec![FsInputProvider::new(input_folder.clone())];
+    if let Some(shared_input_folder) = &settings.shared_input_folder {
+        input_providers.push(FsInputProvider::new(profile.input_folder(shared_input_folder)));
+    }
+    let years = match year {
+        Some(year) => vec![year],
+        None => AOC_PROBLEMS_SOLVER.iter().map(|(year, _)| *year).collect(),
+    };
+    let client = if calendar { Some(build_client(settings)?) } else { None };
+    for (i, year) in years.into_iter().enumerate() {
+        let stars = client.as_ref().map(|client| get_calendar_stars(client, year)).transpose()?;
+        let statuses = day_statuses(
+            year,
+            get_year_solver(year),
+            &input_providers,
+            &input_folder,
+            stars.as_ref(),
+        )?;
+        if i > 0 {
+            println!();
+        }
+        print!("{}", render_status(year, &statuses)?);
+    }
+    Ok(())
+}
+
+fn store(settings: &Settings, command: &StoreCommand) -> Result<()> {
+    let profile = Profile::new(settings.profile.clone())?;
+    let input_folder = profile.input_folder(&settings.input_folder());
+    let require_cipher = || {
+        input_cipher(settings)?.context(format!(
+            "Input key is required, set env var {:?} or use --input-key-file",
+            INPUT_KEY_ENV_VAR
+        ))
+    };
+    match command {
+        StoreCommand::Keygen { path } => {
+            InputCipher::generate_key_file(path)?;
+            println!("Generated input key {:?}", path);
+        }
+        StoreCommand::Rekey { new_key_file } => {
+            let (count, plain_count) = rekey(
+                &input_folder,
+                &require_cipher()?,
+                &InputCipher::from_key_file(new_key_file)?,
+            )?;
+            println!("Re-encrypted {} inputs in {:?}", count, input_folder);
+            if plain_count > 0 {
+                println!("Encrypted and removed {} plain inputs", plain_count);
+            }
+        }
+        StoreCommand::Export { output_folder } => {
+            let count = export(&input_folder, &require_cipher()?, output_folder)?;
+            println!("Exported {} inputs to {:?}", count, output_folder);
+        }
+    }
+    Ok(())
+}
+
+fn input_cipher(settings: &Settings) -> Result<Option<InputCipher>> {
+    if let Ok(key) = std::env::var(INPUT_KEY_ENV_VAR) {
+        return Ok(Some(InputCipher::from_hex(&key)?));
+    }
+    settings.input_key_file.as_deref().map(InputCipher::from_key_file).transpose()
+}
+
+fn read(settings: &Settings, year: u16, day: u8, samples: bool) -> Result<()> {
+    ensure_unlocked(&SystemClock, year, day, false)?;
+    let profile = Profile::new(settings.profile.clone())?;
+    let input_folder = profile.input_folder(&settings.input_folder());
+    let description = get_description(&build_client(settings)?, &input_folder, year, day)?;
+    if samples {
+        description
+            .samples
+            .iter()
+            .for_each(|sample| println!("{}", PuzzleDescription::sample_as_test_const(sample)));
+    } else {
+        println!("{}", description.markdown);
+    }
+    Ok(())
+}
+
+fn solve(args: &Args, settings: Settings) -> Result<()> {
+    let Some(year) = settings.year else {
+        bail!("Year is required to solve, use --year or set it in env var or config file.");
+    };
+    rayon::ThreadPoolBuilder::default().num_threads(settings.threads()).build_global().unwrap();
+    let input_provider = build_input_provider(&settings, args.wait)?;
+    let answer_folder =
+        Profile::new(settings.profile.clone())?.input_folder(&settings.input_folder());
+    let output_format = settings.output_format();
+    set_solver_params(settings.solver_params);
+    let solvers = get_year_solver(year);
+    let (day_mapper_solvers, mut days) = match solvers {
+        None => bail!(format!("There is no solver for selected year {}", year)),
+        Some(day_mapper_solvers) => {
             if args.days.is_empty() {
-                (*entry.1, entry.1.keys().copied().collect::<Vec<u8>>())
+                (day_mapper_solvers, day_mapper_solvers.keys().copied().collect::<Vec<u8>>())
             } else {
-                (*entry.1, args.days)
+                (day_mapper_solvers, args.days.clone())
             }
         }
     };
     days.sort();
+    let mut frame_sink = args
+        .visualize
+        .map(|format| {
+            new_sink(
+                format,
+                args.visualize_output.as_deref(),
+                Duration::from_millis(args.frame_delay_ms),
+                args.frame_scale,
+            )
+        })
+        .transpose()?;
 
     let mut failed = false;
     for day in days {
-        if let Some((_, solver_fn)) = day_mapper_solvers.get_entry(&day) {
+        if let Some(day_solver) = day_mapper_solvers.get(&day) {
+            let alloc_span = alloc_stats::start();
             let start = SystemTime::now();
-            let result = solver_fn(args.year, day, &args.input_folder, &args.session_file)?;
+            let result = (day_solver.solve)(year, day, &input_provider)?;
             let duration = SystemTime::now().duration_since(start)?;
-            println!("{0}.{1}. Result for year {0} day {1} is:", args.year, day);
-            println!("    {result}");
-            println!("  Runtime: {duration:?}");
-            println!();
+            let alloc_stats = alloc_span.as_ref().map(alloc_stats::finish);
+            let result = result.to_string();
+            let recorded_answer = read_answer(&answer_folder, year, day)?;
+            let verified = recorded_answer.as_ref().map(|answer| *answer == result);
+            if args.record_answers && verified != Some(true) {
+                record_answer(&answer_folder, year, day, &result)?;
+            } else if verified == Some(false) {
+                eprintln!(
+                    "{0}.{1}. Result for year {0} day {1} differs from recorded answer {2}",
+                    year,
+                    day,
+                    recorded_answer.unwrap_or_default()
+                );
+                failed = true;
+            }
+            match output_format {
+                OutputFormat::Text => {
+                    println!("{0}.{1}. Result for year {0} day {1} is:", year, day);
+                    println!("    {result}");
+                    println!("  Runtime: {duration:?}");
+                    if let Some(alloc_stats) = alloc_stats {
+                        println!("  Memory: {alloc_stats}");
+                    }
+                    println!();
+                }
+                OutputFormat::Json => println!(
+                    "{}",
+                    serde_json::json!({
+                        "year": year,
+                        "day": day,
+                        "result": result,
+                        "verified": verified,
+                        "runtime_ms": duration.as_secs_f64() * 1000.0,
+                        "memory": alloc_stats,
+                    })
+                ),
+            }
+            if let Some(frame_sink) = frame_sink.as_mut() {
+                match get_day_visualizer(year, day) {
+                    Some(visualize_fn) => visualize_fn(
+                        &input_provider.require_input(year, day)?,
+                        frame_sink.as_mut(),
+                    )?,
+                    None => eprintln!("{0}.{1}. No visualization for year {0} day {1}.", year, day),
+                }
+            }
         } else {
-            eprintln!("{0}.{1}. There is no solver for year {0} day {1}.", args.year, day);
+            eprintln!("{0}.{1}. There is no solver for year {0} day {1}.", year, day);
             failed = true;
         }
     }
@@ -79,24 +545,74 @@ fn main() -> Result<()> {
     Ok(())
 }
 
-#[cfg(test)]
+fn build_client(settings: &Settings) -> Result<AocClient> {
+    let profile = Profile::new(settings.profile.clone())?;
+    AocClient::new(
+        profile
+            .session(&settings.session_file(), &settings.input_folder(), |k| std::env::var(k).ok()),
+        AocClientConfig {
+            user_agent: settings.user_agent(),
+            min_request_interval: settings.request_interval(),
+            timeout: settings.request_timeout(),
+            max_retries: settings.max_retries(),
+            ..AocClientConfig::default()
+        },
+    )
+}
+
+/// Input folders, without downloading.
+fn local_input_providers(settings: &Settings) -> Result<Vec<Box<dyn InputProvider>>> {
+    let profile = Profile::new(settings.profile.clone())?;
+    let mut providers: Vec<Box<dyn InputProvider>> = vec![Box::new(
+        FsInputProvider::new(profile.input_folder(&settings.input_folder()))
+            .with_cipher(input_cipher(settings)?),
+    )];
+    if let Some(shared_input_folder) = &settings.shared_input_folder {
+        providers.push(Box::new(
+            FsInputProvider::new(profile.input_folder(shared_input_folder))
+                .with_cipher(input_cipher(settings)?),
+        ));
+    }
+    Ok(providers)
+}
+
+fn build_input_provider(settings: &Settings, wait: bool) -> Result<ChainedInputProvider> {
+    let mut providers = local_input_providers(settings)?;
+    let client = build_client(settings)?;
+    providers.push(Box::new(HttpInputProvider::new(client, Box::new(SystemClock), wait)));
+    Ok(ChainedInputProvider::new(providers))
+}
+
+#[cfg(all(test, feature = "y2023"))]
 mod tests {
     use std::path::PathBuf;
 
     use anyhow::Result;
 
-    use crate::solver::AOC_PROBLEMS_SOLVER;
+    use crate::client::session::Session;
+    use crate::client::{AocClient, AocClientConfig};
+    use crate::input::chained::ChainedInputProvider;
+    use crate::input::fs::FsInputProvider;
+    use crate::input::http::HttpInputProvider;
+    use crate::input::unlock::SystemClock;
+    use crate::solver::get_year_solver;
 
     const SESSION_PATH: &str = "data/session.txt";
     const INPUT_FOLDER_PATH: &str = "data";
 
     fn run(year: u16, day: u8) -> Result<()> {
-        let result = AOC_PROBLEMS_SOLVER[&year][&day](
-            year,
-            day,
-            &PathBuf::from(&INPUT_FOLDER_PATH),
-            &PathBuf::from(&SESSION_PATH),
-        )?;
+        let input_provider = ChainedInputProvider::new(vec![
+            Box::new(FsInputProvider::new(PathBuf::from(&INPUT_FOLDER_PATH))),
+            Box::new(HttpInputProvider::new(
+                AocClient::new(
+                    Session::File(PathBuf::from(&SESSION_PATH)),
+                    AocClientConfig::default(),
+                )?,
+                Box::new(SystemClock),
+                false,
+            )),
+        ]);
+        let result = (get_year_solver(year).unwrap()[&day].solve)(year, day, &input_provider)?;
         println!("Result for year {year} day {day} is:");
         println!("{}", result);
         Ok(())
@@ -0,0 +1,231 @@
+use std::collections::BTreeMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Response, Server};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{warn, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use crate::solver::{get_year_solver, AOC_PROBLEMS_SOLVER};
+
+/// Phase name ("parse", "solve", "part_1", ...) to total milliseconds.
+type Timings = Arc<Mutex<BTreeMap<String, f64>>>;
+
+/// Records how long `parse` and `part` spans of a solve took.
+struct TimingLayer(Timings);
+
+struct SpanStart {
+    name: String,
+    start: Instant,
+}
+
+#[derive(Default)]
+struct PartVisitor(Option<u64>);
+
+impl Visit for PartVisitor {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "part" {
+            self.0 = Some(value);
+        }
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record_u64(field, value as u64);
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for TimingLayer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = PartVisitor::default();
+        attrs.record(&mut visitor);
+        let name = match (attrs.metadata().name(), visitor.0) {
+            ("part", Some(part)) => format!("part_{}", part),
+            (name, _) => name.to_owned(),
+        };
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanStart { name, start: Instant::now() });
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let extensions = span.extensions();
+        if let Some(SpanStart { name, start }) = extensions.get::<SpanStart>() {
+            let elapsed = start.elapsed().as_secs_f64() * 1000.0;
+            *self.0.lock().unwrap().entry(name.clone()).or_default() += elapsed;
+        }
+    }
+}
+
+/// Answer as json number when it fits, warning of a
+/// [`crate::utils::WarningResult`] is split off.
+fn typed_answer(part: u8, answer: &str) -> Value {
+    let (answer, warning) = match answer.split_once(" --") {
+        Some((answer, warning)) => (answer, Some(warning.trim_end_matches("--"))),
+        None => (answer, None),
+    };
+    let answer = match (answer.parse::<i64>(), answer.parse::<u64>()) {
+        (Ok(number), _) => json!(number),
+        (_, Ok(number)) => json!(number),
+        _ => json!(answer),
+    };
+    json!({ "part": part, "answer": answer, "warning": warning })
+}
+
+fn solvers_json(year: u16) -> Option<Value> {
+    let mut days = get_year_solver(year)?.keys().copied().collect::<Vec<_>>();
+    days.sort();
+    Some(json!({ "year": year, "days": days }))
+}
+
+fn solve(year: u16, day: u8, part: Option<u8>, input: &str) -> (u16, Value) {
+    let Some(day_solver) = get_year_solver(year).and_then(|solvers| solvers.get(&day)) else {
+        return (404, json!({ "error": format!("No solver for {} day {}", year, day) }));
+    };
+    let timings = Timings::default();
+    let subscriber = tracing_subscriber::registry().with(TimingLayer(timings.clone()));
+    let start = Instant::now();
+    let answers = tracing::subscriber::with_default(subscriber, || {
+        catch_unwind(AssertUnwindSafe(|| (day_solver.solve_parts)(input, part)))
+    });
+    let total = start.elapsed();
+    let answers = match answers {
+        Ok(Ok(answers)) => answers,
+        Ok(Err(e)) => return (400, json!({ "error": format!("{:#}", e) })),
+        Err(_) => return (500, json!({ "error": "Solver panicked" })),
+    };
+    let mut timings = timings.lock().unwrap().clone();
+    timings.insert("total".to_owned(), total.as_secs_f64() * 1000.0);
+    (
+        200,
+        json!({
+            "year": year,
+            "day": day,
+            "answers": answers
+                .iter()
+                .map(|(part, answer)| typed_answer(*part, &answer.to_string()))
+                .collect::<Vec<_>>(),
+            "timings_ms": timings,
+        }),
+    )
+}
+
+/// Route one request, returns status and json body.
+///
+/// - `GET /solvers`: every year with its days.
+/// - `GET /solvers/{year}`: days of one year.
+/// - `POST /solve/{year}/{day}[?part={part}]`: solve input given as body.
+pub fn handle(method: &Method, url: &str, body: &str) -> (u16, Value) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    let bad_request = |message: String| (400, json!({ "error": message }));
+    let parse_segment = |segment: &str| -> Result<u16> {
+        segment.parse().map_err(|_| anyhow!("Invalid number {:?} in path", segment))
+    };
+
+    match (method, segments.as_slice()) {
+        (Method::Get, ["solvers"]) => {
+            let mut years = AOC_PROBLEMS_SOLVER.iter().map(|(year, _)| *year).collect::<Vec<_>>();
+            years.sort();
+            (200, Value::Array(years.into_iter().filter_map(solvers_json).collect()))
+        }
+        (Method::Get, ["solvers", year]) => match parse_segment(year) {
+            Ok(year) => solvers_json(year).map_or_else(
+                || (404, json!({ "error": format!("No solver for year {}", year) })),
+                |solvers| (200, solvers),
+            ),
+            Err(e) => bad_request(e.to_string()),
+        },
+        (Method::Post, ["solve", year, day]) => {
+            let part = query
+                .split('&')
+                .find_map(|param| param.strip_prefix("part="))
+                .map(|part| part.parse::<u8>().map_err(|_| anyhow!("Invalid part {:?}", part)))
+                .transpose();
+            match (parse_segment(year), parse_segment(day), part) {
+                (Ok(year), Ok(day), Ok(part)) => match u8::try_from(day) {
+                    Ok(day) => solve(year, day, part, body),
+                    Err(_) => bad_request(format!("Invalid day {}", day)),
+                },
+                (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => bad_request(e.to_string()),
+            }
+        }
+        _ => (404, json!({ "error": format!("Not found: {} {}", method, path) })),
+    }
+}
+
+/// Serve solvers over http until the process is killed, requests are solved
+/// one at a time.
+pub fn serve(bind: &str, port: u16) -> Result<()> {
+    let server = Server::http((bind, port)).map_err(|e| anyhow!("Failed to bind: {}", e))?;
+    eprintln!("Serving solvers on http://{}", server.server_addr());
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        let (status, json) = match request.as_reader().read_to_string(&mut body) {
+            Ok(_) => handle(request.method(), request.url(), &body),
+            Err(e) => (400, json!({ "error": format!("Invalid body: {}", e) })),
+        };
+        let response = Response::from_string(json.to_string())
+            .with_status_code(status)
+            .with_header(content_type.clone());
+        if let Err(e) = request.respond(response) {
+            warn!(error = %e, "failed to respond");
+        }
+    }
+    Ok(())
+}
+
+/// Tests solve 2023 day 1 so they need that year compiled in.
+#[cfg(all(test, feature = "y2023"))]
+mod tests {
+    use indoc::indoc;
+    use serde_json::json;
+    use tiny_http::Method;
+
+    use crate::serve::handle;
+
+    const INPUT: &str = indoc! {"
+        1abc2
+        pqr3stu8vwx
+        a1b2c3d4e5f
+        treb7uchet
+    "};
+
+    #[test]
+    fn test_list_solvers() {
+        let (status, json) = handle(&Method::Get, "/solvers/2023", "");
+        assert_eq!(status, 200);
+        assert_eq!(json["days"][0], 1);
+        assert_eq!(handle(&Method::Get, "/solvers/1999", "").0, 404);
+        assert_eq!(handle(&Method::Get, "/solvers/abc", "").0, 400);
+        assert!(handle(&Method::Get, "/solvers", "").1.as_array().is_some_and(|y| !y.is_empty()));
+    }
+
+    #[test]
+    fn test_solve() {
+        let (status, json) = handle(&Method::Post, "/solve/2023/1?part=1", INPUT);
+        assert_eq!(status, 200);
+        assert_eq!(json["answers"], json!([{ "part": 1, "answer": 142, "warning": null }]));
+        assert!(json["timings_ms"]["parse"].is_number());
+        assert!(json["timings_ms"]["part_1"].is_number());
+
+        let (status, json) = handle(&Method::Post, "/solve/2023/1", INPUT);
+        assert_eq!(status, 200);
+        assert_eq!(json["answers"].as_array().map(Vec::len), Some(2));
+        assert_eq!(handle(&Method::Post, "/solve/2023/1?part=3", INPUT).0, 400);
+        assert_eq!(handle(&Method::Post, "/solve/2023/26", INPUT).0, 404);
+        assert_eq!(handle(&Method::Get, "/solve/2023/1", INPUT).0, 404);
+    }
+}
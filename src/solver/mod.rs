@@ -1,37 +1,126 @@
+use std::collections::HashMap;
 use std::fmt::Display;
-use std::path::Path;
+#[cfg(feature = "solvers")]
 use std::str::FromStr;
+use std::sync::OnceLock;
 
+#[cfg(feature = "y2023")]
+use anyhow::Context;
 use anyhow::Result;
-use phf::{phf_map, Map};
+use phf::Map;
+#[cfg(feature = "solvers")]
 use thiserror::Error;
+#[cfg(feature = "solvers")]
+use tracing::debug_span;
 
+use crate::config::ParamKey;
+use crate::input::InputProvider;
+#[cfg(feature = "y2021")]
 use crate::solver::y2021::Y2021_SOLVER;
-use crate::solver::y2023::Y2023_SOLVER;
+#[cfg(feature = "y2023")]
+use crate::solver::y2023::{Y2023_SOLVER, Y2023_VISUALIZER};
+#[cfg(feature = "solvers")]
 use crate::utils::Result2Parts;
+use crate::visualize::FrameSink;
 
+#[cfg(feature = "y2021")]
 pub mod y2021;
+#[cfg(feature = "y2023")]
 pub mod y2023;
 
-pub const AOC_PROBLEMS_SOLVER: Map<
-    u16,
-    &Map<u8, fn(u16, u8, &Path, &Path) -> Result<Box<dyn Display>>>,
-> = phf_map! {
-    2023_u16 => &Y2023_SOLVER,
-    2021_u16 => &Y2021_SOLVER
-};
+pub type SolverFn = fn(u16, u8, &dyn InputProvider) -> Result<Box<dyn Display>>;
 
+/// Parse input once then solve the given part, or every part when `None`.
+pub type PartsSolverFn = fn(&str, Option<u8>) -> Result<Vec<(u8, Box<dyn Display>)>>;
+
+/// Entry of the solver registry, see [`crate::utils::boxed_try_get_input_and_solve`].
+#[derive(Copy, Clone)]
+pub struct DaySolver {
+    pub solve: SolverFn,
+    pub solve_parts: PartsSolverFn,
+}
+
+pub type DaySolverMap = Map<u8, DaySolver>;
+
+/// Solvers of every year compiled in, each year is gated behind its own cargo
+/// feature (`y2021`, `y2023`, ...).
+pub const AOC_PROBLEMS_SOLVER: &[(u16, &DaySolverMap)] = &[
+    #[cfg(feature = "y2023")]
+    (2023_u16, &Y2023_SOLVER),
+    #[cfg(feature = "y2021")]
+    (2021_u16, &Y2021_SOLVER),
+];
+
+pub fn get_year_solver(year: u16) -> Option<&'static DaySolverMap> {
+    AOC_PROBLEMS_SOLVER.iter().find(|(y, _)| *y == year).map(|(_, solvers)| *solvers)
+}
+
+/// Parse input then emit frames, see [`crate::visualize::Visualize`].
+pub type VisualizeFn = fn(&str, &mut dyn FrameSink) -> Result<()>;
+
+pub type DayVisualizerMap = Map<u8, VisualizeFn>;
+
+pub const AOC_PROBLEMS_VISUALIZER: &[(u16, &DayVisualizerMap)] = &[
+    #[cfg(feature = "y2023")]
+    (2023_u16, &Y2023_VISUALIZER),
+];
+
+pub fn get_day_visualizer(year: u16, day: u8) -> Option<VisualizeFn> {
+    AOC_PROBLEMS_VISUALIZER
+        .iter()
+        .find(|(y, _)| *y == year)
+        .and_then(|(_, visualizers)| visualizers.get(&day).copied())
+}
+
+fn solver_params() -> &'static OnceLock<HashMap<ParamKey, String>> {
+    static SOLVER_PARAMS: OnceLock<HashMap<ParamKey, String>> = OnceLock::new();
+    &SOLVER_PARAMS
+}
+
+/// Set parameters read by [`solver_param`], only the first call has effect.
+pub fn set_solver_params(params: HashMap<ParamKey, String>) {
+    let _ = solver_params().set(params);
+}
+
+#[cfg(feature = "y2023")]
+/// Solver parameter from config file, env var or command line, `default` when
+/// not set.
+pub fn solver_param<T: FromStr>(year: u16, day: u8, name: &str, default: T) -> Result<T> {
+    match solver_params().get().and_then(|params| params.get(&(year, day, name.to_owned()))) {
+        None => Ok(default),
+        Some(value) => value.parse().ok().with_context(|| {
+            format!("Invalid solver parameter y{}.day{}.{}: {:?}", year, day, name, value)
+        }),
+    }
+}
+
+#[cfg(feature = "solvers")]
 #[derive(Error, Debug)]
 pub enum Error {
     #[error(transparent)]
     InputParseError(#[from] anyhow::Error),
+    #[error("There is no part {0}, this solver has {1} part(s)")]
+    NoSuchPart(u8, u8),
 }
 
+#[cfg(feature = "solvers")]
 pub trait ProblemSolver: FromStr<Err = anyhow::Error> {
-    type SolutionType: Display;
+    type SolutionType: Display + 'static;
+    /// Number of parts accepted by [`ProblemSolver::solve_part`].
+    const PARTS: u8 = 1;
+
     fn solve(&self) -> Result<Self::SolutionType>;
+
+    /// Answer of one part, solvers giving a single answer only have part 1.
+    fn solve_part(&self, part: u8) -> Result<Box<dyn Display>> {
+        match part {
+            1 => Ok(Box::new(self.solve()?)),
+            _ => Err(Error::NoSuchPart(part, Self::PARTS))?,
+        }
+    }
 }
 
+#[cfg(feature = "solvers")]
 pub trait TwoPartsProblemSolver: FromStr<Err = anyhow::Error> {
     type Solution1Type: Display;
     type Solution2Type: Display;
@@ -40,18 +129,32 @@ pub trait TwoPartsProblemSolver: FromStr<Err = anyhow::Error> {
     fn solve_2(&self) -> Result<Self::Solution2Type>;
 }
 
+#[cfg(feature = "solvers")]
 impl<T, T1, T2> ProblemSolver for T
 where
     T: TwoPartsProblemSolver<Solution1Type = T1, Solution2Type = T2>,
-    T1: Display,
-    T2: Display,
+    T1: Display + 'static,
+    T2: Display + 'static,
 {
     type SolutionType = Result2Parts<T1, T2>;
+    const PARTS: u8 = 2;
+
     fn solve(&self) -> Result<Result2Parts<T1, T2>> {
-        Ok(Result2Parts::new(self.solve_1()?, self.solve_2()?))
+        let res_1 = debug_span!("part", part = 1).in_scope(|| self.solve_1())?;
+        let res_2 = debug_span!("part", part = 2).in_scope(|| self.solve_2())?;
+        Ok(Result2Parts::new(res_1, res_2))
+    }
+
+    fn solve_part(&self, part: u8) -> Result<Box<dyn Display>> {
+        match part {
+            1 => Ok(Box::new(self.solve_1()?)),
+            2 => Ok(Box::new(self.solve_2()?)),
+            _ => Err(Error::NoSuchPart(part, Self::PARTS))?,
+        }
     }
 }
 
+#[cfg(feature = "y2023")]
 macro_rules! combine_solver {
     ($wrapper:ident, $solver1:ident, $solver2:ident ) => {
         pub struct $wrapper($solver1, $solver2);
@@ -79,6 +182,7 @@ macro_rules! combine_solver {
     };
 }
 
+#[cfg(feature = "y2023")]
 macro_rules! share_struct_solver {
     ($wrapper:ident, $solver1:ident, $solver2:ident ) => {
         pub struct $wrapper(std::rc::Rc<$solver1>, $solver2);
@@ -115,6 +219,7 @@ macro_rules! share_struct_solver {
     };
 }
 
+#[cfg(feature = "y2023")]
 macro_rules! share_struct_parallel_solver {
     ($wrapper:ident, $solver1:ident, $solver2:ident ) => {
         pub struct $wrapper(std::sync::Arc<$solver1>, $solver2);
@@ -151,6 +256,9 @@ macro_rules! share_struct_parallel_solver {
     };
 }
 
+#[cfg(feature = "y2023")]
 pub(crate) use combine_solver;
+#[cfg(feature = "y2023")]
 pub(crate) use share_struct_parallel_solver;
+#[cfg(feature = "y2023")]
 pub(crate) use share_struct_solver;
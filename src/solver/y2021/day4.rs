@@ -73,8 +73,8 @@ mod tests {
     use anyhow::Result;
     use indoc::indoc;
 
+    use crate::solver::y2021::day4::Day4;
     use crate::solver::TwoPartsProblemSolver;
-    use crate::solver::y2023::day4::Day4;
 
     const SAMPLE_INPUT: &str = indoc! {"
             Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
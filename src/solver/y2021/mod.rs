@@ -1,21 +1,34 @@
-use std::fmt::Display;
-use std::path::Path;
-
-use anyhow::Result;
-use phf::{phf_map, Map};
+use phf::phf_map;
 
 use crate::solver::y2021::day1::Day1;
 use crate::solver::y2021::day2::Day2;
 use crate::solver::y2021::day3::Day3;
+#[cfg(feature = "wip")]
+use crate::solver::y2021::day4::Day4;
+use crate::solver::DaySolverMap;
 use crate::utils::boxed_try_get_input_and_solve;
 
 pub mod day1;
 pub mod day2;
 pub mod day3;
-// pub mod day4;
+#[cfg(feature = "wip")]
+pub mod day4;
+
+/// `phf_map!` cannot gate single entries, so days are listed once here and
+/// the unfinished ones are passed in with the `wip` feature.
+macro_rules! y2021_solver {
+    ($($wip_day:literal => $wip_solver:ty),* $(,)?) => {
+        phf_map! {
+            1_u8 => boxed_try_get_input_and_solve!(Day1),
+            2_u8 => boxed_try_get_input_and_solve!(Day2),
+            3_u8 => boxed_try_get_input_and_solve!(Day3),
+            $($wip_day => boxed_try_get_input_and_solve!($wip_solver),)*
+        }
+    };
+}
+
+#[cfg(not(feature = "wip"))]
+pub const Y2021_SOLVER: DaySolverMap = y2021_solver!();
 
-pub const Y2021_SOLVER: Map<u8, fn(u16, u8, &Path, &Path) -> Result<Box<dyn Display>>> = phf_map! {
-    1_u8 => boxed_try_get_input_and_solve!(Day1),
-    2_u8 => boxed_try_get_input_and_solve!(Day2),
-    3_u8 => boxed_try_get_input_and_solve!(Day3),
-};
+#[cfg(feature = "wip")]
+pub const Y2021_SOLVER: DaySolverMap = y2021_solver!(4_u8 => Day4);
@@ -14,6 +14,7 @@ use crate::solver::{share_struct_solver, ProblemSolver};
 use crate::utils::graph::dfs;
 use crate::utils::grid::grid_2d_vec::Grid2dVec;
 use crate::utils::grid::{Grid2d, GridDirection};
+use crate::visualize::{Cell, Frame, FrameSink, Rgb, Visualize};
 
 share_struct_solver!(Day10, Day10Part1, Day10Part2);
 
@@ -26,9 +27,6 @@ pub struct Day10Part1 {
 #[derive(Deref)]
 pub struct Day10Part2(Rc<Day10Part1>);
 
-const CARDINAL: &[GridDirection; 4] =
-    &[GridDirection::North, GridDirection::South, GridDirection::East, GridDirection::West];
-
 const HORIZONTAL_PIPE: &Pipe = &Pipe::new(enum_set!(GridDirection::West | GridDirection::East));
 
 const VERTICAL_PIPE: &Pipe = &Pipe::new(enum_set!(GridDirection::South | GridDirection::North));
@@ -118,31 +116,15 @@ impl FromStr for Day10Part1 {
 
     fn from_str(s: &str) -> Result<Self> {
         let starting_position = OnceCell::default();
-        let grid = Grid2dVec::<PositionKind>::try_new(s.lines().map(str::bytes).enumerate().map(
-            |(y, iter)| {
-                let starting_position = &starting_position;
-                iter.enumerate().map(move |(x, b)| {
-                    let position_kind_res = PositionKind::try_from(b);
-                    match position_kind_res {
-                        Ok(position_kind) => match position_kind {
-                            PositionKind::Start => {
-                                if let Err(existing_start) = starting_position.set((x, y)) {
-                                    bail!(
-                                        "Found 2 starting pos {:?} and {:?}",
-                                        existing_start,
-                                        (x, y)
-                                    )
-                                }
-                                Ok(PositionKind::Start)
-                            }
-                            PositionKind::Ground => Ok(PositionKind::Ground),
-                            PositionKind::Pipe(p) => Ok(PositionKind::Pipe(p)),
-                        },
-                        Err(e) => Err(e),
-                    }
-                })
-            },
-        ))?;
+        let grid = Grid2dVec::parse_with(s, |x, y, b| {
+            let position_kind = PositionKind::try_from(b)?;
+            if position_kind == PositionKind::Start {
+                if let Err(existing_start) = starting_position.set((x, y)) {
+                    bail!("Found 2 starting pos {:?} and {:?}", existing_start, (x, y))
+                }
+            }
+            Ok(position_kind)
+        })?;
 
         Ok(Day10Part1 {
             grid,
@@ -256,8 +238,10 @@ impl Day10Part1 {
     }
 
     fn find_pipe_loop(&self) -> Result<ChainPathRc> {
-        let result =
-            CARDINAL.iter().map(|direction| (self.start, *direction)).find_map(|start_state| {
+        let result = GridDirection::CARDINAL
+            .iter()
+            .map(|direction| (self.start, *direction))
+            .find_map(|start_state| {
                 dfs(
                     start_state,
                     move |((prev_x, prev_y), prev_state_face)| {
@@ -309,6 +293,52 @@ impl Day10Part1 {
     }
 }
 
+impl PositionKind {
+    fn to_box_char(self) -> char {
+        match self {
+            PositionKind::Start => 'S',
+            PositionKind::Ground => '.',
+            PositionKind::Pipe(PipeKind::Horizontal) => '─',
+            PositionKind::Pipe(PipeKind::Vertical) => '│',
+            PositionKind::Pipe(PipeKind::LNorthEast) => '└',
+            PositionKind::Pipe(PipeKind::LNorthWest) => '┘',
+            PositionKind::Pipe(PipeKind::LSouthWest) => '┐',
+            PositionKind::Pipe(PipeKind::LSouthEast) => '┌',
+        }
+    }
+}
+
+impl Visualize for Day10Part1 {
+    /// Pipes drawn with box characters, the loop lighting up from the start
+    /// in about 50 frames.
+    fn visualize(&self, sink: &mut dyn FrameSink) -> Result<()> {
+        let path = self.get_pipe_path().clone().map_err(|e| anyhow!(e))?;
+        let mut path = path.iter().map(|(position, _)| position).collect::<Vec<_>>();
+        path.reverse();
+
+        let mut frame = Frame::new(
+            self.grid.width(),
+            self.grid.height(),
+            Cell { ch: ' ', color: Rgb(0, 0, 0) },
+        );
+        self.grid.rows().enumerate().for_each(|(y, row)| {
+            row.iter().enumerate().for_each(|(x, kind)| {
+                frame.set(x, y, Cell { ch: kind.to_box_char(), color: Rgb(70, 70, 70) })
+            })
+        });
+        sink.push(&frame)?;
+        let chunk_size = path.len().div_ceil(50).max(1);
+        for chunk in path.chunks(chunk_size) {
+            chunk.iter().for_each(|(x, y)| {
+                let ch = self.grid[(*x, *y)].to_box_char();
+                frame.set(*x, *y, Cell { ch, color: Rgb(255, 200, 0) })
+            });
+            sink.push(&frame)?;
+        }
+        Ok(())
+    }
+}
+
 impl ProblemSolver for Day10Part2 {
     type SolutionType = usize;
 
@@ -386,13 +416,19 @@ impl ProblemSolver for Day10Part2 {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
     use std::str::FromStr;
 
     use anyhow::Result;
     use indoc::indoc;
 
-    use crate::solver::y2023::day10::Day10;
+    use crate::solver::y2023::day10::{Day10, Day10Part1};
     use crate::solver::TwoPartsProblemSolver;
+    use crate::utils::grid::grid_2d_vec::Grid2dVec;
+    use crate::utils::grid::point::Vec2;
+    use crate::utils::grid::region::Grid2dRegion;
+    use crate::utils::grid::Grid2d;
+    use crate::visualize::{Rgb, VecSink, Visualize};
 
     const SAMPLE_INPUT_1: &str = indoc! {"
             ..F7.
@@ -426,4 +462,55 @@ mod tests {
         assert_eq!(Day10::from_str(SAMPLE_INPUT_2)?.solve_2()?, 10);
         Ok(())
     }
+
+    /// Count tiles enclosed by the loop by flood filling the outside of the
+    /// loop drawn at 3 times the scale, so squeezing between pipes is a gap.
+    fn count_inside_by_flood_fill(day: &Day10Part1) -> Result<usize> {
+        let path = day.get_pipe_path().as_ref().map_err(|e| anyhow::anyhow!(e.clone()))?;
+        let start_enter = path.position_and_facing.1.reverse();
+        let mut walls = HashSet::new();
+        path.iter().for_each(|((x, y), (enter, exit))| {
+            let center = (3 * x as isize + 1, 3 * y as isize + 1);
+            walls.insert(center);
+            [enter.unwrap_or(start_enter), exit].into_iter().map(Vec2::<isize>::from).for_each(
+                |offset| {
+                    walls.insert((center.0 + offset.x, center.1 + offset.y));
+                },
+            );
+        });
+        let scaled = Grid2dVec::from_fn(3 * day.grid.width(), 3 * day.grid.height(), |x, y| {
+            walls.contains(&(x as isize, y as isize))
+        });
+        let outside = scaled.flood_fill(0, 0, |_, wall| !wall);
+        Ok((0..day.grid.height())
+            .flat_map(|y| (0..day.grid.width()).map(move |x| (x, y)))
+            .filter(|&(x, y)| !outside.contains(3 * x + 1, 3 * y + 1))
+            .filter(|&(x, y)| !walls.contains(&(3 * x as isize + 1, 3 * y as isize + 1)))
+            .count())
+    }
+
+    #[test]
+    fn test_solve_2_matches_flood_fill() -> Result<()> {
+        for input in [SAMPLE_INPUT_1, SAMPLE_INPUT_2] {
+            let expected = Day10::from_str(input)?.solve_2()?;
+            assert_eq!(count_inside_by_flood_fill(&Day10Part1::from_str(input)?)?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_visualize() -> Result<()> {
+        let mut sink = VecSink::default();
+        Day10Part1::from_str(SAMPLE_INPUT_1)?.visualize(&mut sink)?;
+        // Untouched grid then one frame per tile of the 16 tiles loop.
+        assert_eq!(sink.0.len(), 17);
+        let lit = |idx: usize| {
+            sink.0[idx].rows().flatten().filter(|cell| cell.color == Rgb(255, 200, 0)).count()
+        };
+        assert_eq!(lit(0), 0);
+        assert_eq!(lit(16), 16);
+        let first_row = sink.0[0].rows().next().unwrap().iter().map(|c| c.ch);
+        assert_eq!(first_row.collect::<String>(), "..┌┐.");
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@ use anyhow::Result;
 use derive_more::{Deref, FromStr};
 use itertools::Itertools;
 
-use crate::solver::{share_struct_solver, ProblemSolver};
+use crate::solver::{share_struct_solver, solver_param, ProblemSolver};
 
 share_struct_solver!(Day11, Day11Part1, Day11Part2);
 
@@ -100,7 +100,8 @@ impl ProblemSolver for Day11Part2 {
     type SolutionType = usize;
 
     fn solve(&self) -> Result<Self::SolutionType> {
-        Ok(self.find_distance_with_expand_factor(1000000))
+        let expand_factor = solver_param(2023, 11, "expand_factor", 1000000)?;
+        Ok(self.find_distance_with_expand_factor(expand_factor))
     }
 }
 
@@ -4,13 +4,13 @@ use std::ops::ControlFlow::{Break, Continue};
 use std::rc::Rc;
 
 use anyhow::{bail, Context, Result};
-use bitvec::field::BitField;
-use bitvec::order::Msb0;
-use bitvec::vec::BitVec;
 use derive_more::{Deref, FromStr};
 
 use crate::solver::{share_struct_solver, ProblemSolver};
 use crate::utils::get_double_newline_regex;
+use crate::utils::grid::grid_2d_bitvec::Grid2dBitVec;
+use crate::utils::grid::transform::Grid2dTransform;
+use crate::utils::grid::Grid2d;
 use crate::utils::int_trait::Integer;
 
 share_struct_solver!(Day13, Day13Part1, Day13Part2);
@@ -35,53 +35,22 @@ impl FromStr for Day13Part1 {
         let grids = double_newline_regex
             .split(s.trim_end())
             .map(|grid| {
-                let (horizontals, vertical_bitvecs, _) = grid
-                    .lines()
-                    .map(|line| {
-                        line.bytes().map(|b| match b {
-                            b'.' => Ok(false),
-                            b'#' => Ok(true),
-                            _ => bail!("Cannot parse character {}", b as char),
-                        })
+                let bits: Grid2dBitVec = Grid2dBitVec::try_new(grid.lines().map(|line| {
+                    line.bytes().map(|b| match b {
+                        b'.' => Ok(false),
+                        b'#' => Ok(true),
+                        _ => bail!("Cannot parse character {}", b as char),
                     })
-                    .try_fold(
-                        (Vec::default(), None, None),
-                        |(mut horizontals, mut verticals, mut len), mut current_line| {
-                            let current_len = current_line.len();
-                            if current_len != *len.get_or_insert(current_len) {
-                                bail!(
-                                    "There is a mismatch horizontal length in following input:\n{}",
-                                    grid
-                                );
-                            }
-                            let mut verticals_mut_iter = verticals
-                                .get_or_insert_with(|| {
-                                    vec![BitVec::<u32, Msb0>::default(); current_len]
-                                })
-                                .iter_mut();
-                            let horizontal_val = current_line
-                                .try_fold(
-                                    BitVec::<u32, Msb0>::with_capacity(current_len),
-                                    |mut bit_vec, current_bool| {
-                                        let curren_bool = current_bool?;
-                                        bit_vec.push(curren_bool);
-                                        verticals_mut_iter.next().unwrap().push(curren_bool);
-                                        Ok::<_, anyhow::Error>(bit_vec)
-                                    },
-                                )?
-                                .load_be::<u32>();
-                            horizontals.push(horizontal_val);
-                            Ok((horizontals, verticals, len))
-                        },
-                    )?;
+                }))
+                .with_context(|| format!("Cannot parse grid:\n{}", grid))?;
+                if bits.width() > 32 || bits.height() > 32 {
+                    bail!("Grid is larger than 32x32:\n{}", grid);
+                }
 
+                // Columns are the rows of the transposed grid.
                 Ok(Day13Grid {
-                    horizontals,
-                    verticals: vertical_bitvecs
-                        .unwrap_or_else(|| Vec::with_capacity(0))
-                        .into_iter()
-                        .map(|b| b.load_be::<u32>())
-                        .collect(),
+                    horizontals: row_values(&bits),
+                    verticals: row_values(&bits.transposed()),
                 })
             })
             .collect::<Result<Vec<_>>>()?;
@@ -90,6 +59,13 @@ impl FromStr for Day13Part1 {
     }
 }
 
+/// Each row as bits, first cell most significant.
+fn row_values<G: Grid2d<bool>>(grid: &G) -> Vec<u32> {
+    (0..grid.height())
+        .map(|y| (0..grid.width()).fold(0, |acc, x| acc << 1 | grid[(x, y)] as u32))
+        .collect()
+}
+
 fn find_mirror_idx<T: PartialEq>(slice: &[T]) -> Option<usize> {
     if slice.len() <= 1 {
         return None;
@@ -13,7 +13,8 @@ use derive_more::{Deref, Display, FromStr};
 use indexmap::IndexSet;
 use itertools::Itertools;
 
-use crate::solver::{share_struct_solver, ProblemSolver};
+use crate::solver::{share_struct_solver, solver_param, ProblemSolver};
+use crate::visualize::{default_color, Frame, FrameSink, Visualize};
 
 share_struct_solver!(Day14, Day14Part1, Day14Part2);
 
@@ -298,6 +299,26 @@ impl ProblemSolver for Day14Part1 {
     }
 }
 
+impl Visualize for Day14Part1 {
+    /// Every tilt of the first "y2023.day14.visualize_cycles" spin cycles.
+    fn visualize(&self, sink: &mut dyn FrameSink) -> Result<()> {
+        let cycles = solver_param(2023, 14, "visualize_cycles", 3)?;
+        let mut current = self.deref().clone();
+        sink.push(&Frame::from_text(&current.to_string(), default_color))?;
+        let tilts = [
+            WeirdGrid::tilt_north,
+            WeirdGrid::tilt_west,
+            WeirdGrid::tilt_south,
+            WeirdGrid::tilt_east,
+        ];
+        for tilt in tilts.iter().cycle().take(4 * cycles) {
+            current = tilt(&current);
+            sink.push(&Frame::from_text(&current.to_string(), default_color))?;
+        }
+        Ok(())
+    }
+}
+
 impl ProblemSolver for Day14Part2 {
     type SolutionType = usize;
 
@@ -338,8 +359,9 @@ mod tests {
     use anyhow::Result;
     use indoc::indoc;
 
-    use crate::solver::y2023::day14::Day14;
+    use crate::solver::y2023::day14::{Day14, Day14Part1};
     use crate::solver::TwoPartsProblemSolver;
+    use crate::visualize::{VecSink, Visualize};
 
     const SAMPLE_INPUT_1: &str = indoc! {"
             O....#....
@@ -365,4 +387,15 @@ mod tests {
         assert_eq!(Day14::from_str(SAMPLE_INPUT_1)?.solve_2()?, 64);
         Ok(())
     }
+
+    #[test]
+    fn test_visualize() -> Result<()> {
+        let mut sink = VecSink::default();
+        Day14Part1::from_str(SAMPLE_INPUT_1)?.visualize(&mut sink)?;
+        assert_eq!(sink.0.len(), 13);
+        let first_row = |idx: usize| sink.0[idx].rows().next().unwrap().iter().map(|c| c.ch);
+        assert_eq!(first_row(0).collect::<String>(), "O....#....");
+        assert_eq!(first_row(1).collect::<String>(), "OOOO.#.O..");
+        Ok(())
+    }
 }
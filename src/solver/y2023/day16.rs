@@ -14,9 +14,10 @@ use rayon::iter::ParallelIterator;
 use thiserror::Error;
 
 use crate::solver::{share_struct_parallel_solver, ProblemSolver};
-use crate::utils::graph::dfs;
+use crate::utils::graph::{bfs, dfs};
 use crate::utils::grid::grid_2d_vec::Grid2dVec;
 use crate::utils::grid::{Grid2d, GridDirection};
+use crate::visualize::{Cell, Frame, FrameSink, Rgb, Visualize};
 
 share_struct_parallel_solver!(Day16, Day16Part1, Day16Part2);
 
@@ -111,9 +112,7 @@ impl FromStr for Day16Part1 {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let grid = Grid2dVec::<PositionKind>::try_new(
-            s.lines().map(str::bytes).map(|iter| iter.map(PositionKind::try_from)),
-        )?;
+        let grid = Grid2dVec::parse_with(s, |_, _, b| PositionKind::try_from(b))?;
 
         Ok(Day16Part1 { grid })
     }
@@ -129,20 +128,7 @@ impl Day16Part1 {
         let visited_pos = Rc::new(RefCell::new(bitvec!(0; self.grid.height() * self.grid.width())));
         dfs(
             (x, y, starting_face),
-            |current_state| {
-                let (x, y, current_face) = *current_state;
-                self.grid
-                    .get(x, y)
-                    .unwrap()
-                    .clone()
-                    .get_next_directions(current_face)
-                    .iter()
-                    .filter_map(move |next_face| {
-                        self.grid
-                            .move_from_coordinate_to_direction(x, y, 1, *next_face)
-                            .map(|(x, y)| (x, y, *next_face))
-                    })
-            },
+            |current_state| self.next_beams(current_state),
             |_, _| false,
             visited_pos.clone(),
             |visited_pos, (x, y, _)| {
@@ -154,6 +140,78 @@ impl Day16Part1 {
         let res = visited_pos.borrow().count_ones();
         Ok(res)
     }
+
+    /// Beams leaving the tile of `current_state`, as position and facing.
+    fn next_beams(
+        &self,
+        &(x, y, current_face): &(usize, usize, GridDirection),
+    ) -> impl Iterator<Item = (usize, usize, GridDirection)> + '_ {
+        self.grid.get(x, y).unwrap().get_next_directions(current_face).iter().filter_map(
+            move |next_face| {
+                self.grid
+                    .move_from_coordinate_to_direction(x, y, 1, *next_face)
+                    .map(|(x, y)| (x, y, *next_face))
+            },
+        )
+    }
+}
+
+impl PositionKind {
+    const fn to_char(self) -> char {
+        match self {
+            PositionKind::Ground => '.',
+            PositionKind::VerticalSplitter => '|',
+            PositionKind::HorizontalSplitter => '-',
+            PositionKind::MirrorNWToSE => '\\',
+            PositionKind::MirrorSWToNE => '/',
+        }
+    }
+}
+
+impl Visualize for Day16Part1 {
+    /// Beams entering from the top left corner, one frame per step. Energized
+    /// tiles are lit and the front of every beam is highlighted.
+    fn visualize(&self, sink: &mut dyn FrameSink) -> Result<()> {
+        let mut fronts: Vec<Vec<(usize, usize)>> = Vec::new();
+        bfs(
+            (0, 0, GridDirection::East),
+            |current_state| self.next_beams(current_state).collect_vec(),
+            |step, (x, y, _)| {
+                if fronts.len() < *step {
+                    fronts.push(Vec::new());
+                }
+                fronts[*step - 1].push((*x, *y));
+                false
+            },
+            0_usize,
+            |prev_step, _| 1 + prev_step,
+        );
+
+        let mut frame = Frame::new(
+            self.grid.width(),
+            self.grid.height(),
+            Cell { ch: ' ', color: Rgb(0, 0, 0) },
+        );
+        self.grid.rows().enumerate().for_each(|(y, row)| {
+            row.iter().enumerate().for_each(|(x, kind)| {
+                frame.set(x, y, Cell { ch: kind.to_char(), color: Rgb(70, 70, 70) })
+            })
+        });
+        let mut previous_front: &[(usize, usize)] = &[];
+        for front in fronts.iter() {
+            previous_front.iter().for_each(|(x, y)| {
+                let ch = self.grid[(*x, *y)].to_char();
+                frame.set(*x, *y, Cell { ch, color: Rgb(200, 120, 0) })
+            });
+            front.iter().for_each(|(x, y)| {
+                let ch = self.grid[(*x, *y)].to_char();
+                frame.set(*x, *y, Cell { ch, color: Rgb(255, 255, 120) })
+            });
+            sink.push(&frame)?;
+            previous_front = front;
+        }
+        Ok(())
+    }
 }
 
 impl ProblemSolver for Day16Part1 {
@@ -201,8 +259,9 @@ mod tests {
     use anyhow::Result;
     use indoc::indoc;
 
-    use crate::solver::y2023::day16::Day16;
+    use crate::solver::y2023::day16::{Day16, Day16Part1};
     use crate::solver::TwoPartsProblemSolver;
+    use crate::visualize::{Rgb, VecSink, Visualize};
 
     const SAMPLE_INPUT_1: &str = indoc! {r"
             .|...\....
@@ -228,4 +287,17 @@ mod tests {
         assert_eq!(Day16::from_str(SAMPLE_INPUT_1)?.solve_2()?, 51);
         Ok(())
     }
+
+    #[test]
+    fn test_visualize() -> Result<()> {
+        let mut sink = VecSink::default();
+        Day16Part1::from_str(SAMPLE_INPUT_1)?.visualize(&mut sink)?;
+        let lit = |idx: usize| {
+            sink.0[idx].rows().flatten().filter(|cell| cell.color != Rgb(70, 70, 70)).count()
+        };
+        assert_eq!(lit(0), 1);
+        assert_eq!(sink.0[0].rows().next().unwrap()[0].color, Rgb(255, 255, 120));
+        assert_eq!(lit(sink.0.len() - 1), 46);
+        Ok(())
+    }
 }
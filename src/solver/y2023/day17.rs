@@ -9,6 +9,8 @@ use thiserror::Error;
 use crate::solver::{share_struct_solver, ProblemSolver};
 use crate::utils::graph::dijkstra_starts_iter;
 use crate::utils::grid::grid_2d_vec::Grid2dVec;
+use crate::utils::grid::neighbor::Grid2dNeighbor;
+use crate::utils::grid::point::Vec2;
 use crate::utils::grid::{Grid2d, GridDirection};
 
 share_struct_solver!(Day17, Day17Part1, Day17Part2);
@@ -56,16 +58,14 @@ impl Day17Part1 {
         let neighbor_iter = [cw_90, ccw_90]
             .into_iter()
             .filter_map(|dir| {
-                self.grid
-                    .move_from_coordinate_to_direction(*x, *y, minimum_block_move_after_turn, dir)
-                    .map(|(x, y)| (x, y, dir))
+                let offset = Vec2::from(dir) * minimum_block_move_after_turn as isize;
+                self.grid.offset_coordinate(*x, *y, offset).map(|(x, y)| (x, y, dir))
             })
             .map(|(moved_x, moved_y, dir)| {
                 let (weight, _, _) = (0_usize..minimum_block_move_after_turn).fold(
                     (weight, *x, *y),
                     |(mut weight, x, y), _step| {
-                        let (x, y) =
-                            self.grid.move_from_coordinate_to_direction(x, y, 1, dir).unwrap();
+                        let (x, y) = self.grid.offset_coordinate(x, y, Vec2::from(dir)).unwrap();
                         weight += self.grid[(x, y)] as usize;
                         (weight, x, y)
                     },
@@ -84,7 +84,7 @@ impl Day17Part1 {
 
         if *can_go_straight != 0 {
             self.grid
-                .move_from_coordinate_to_direction(*x, *y, 1, *face)
+                .offset_coordinate(*x, *y, Vec2::from(*face))
                 .map(|(x, y)| {
                     ((x, y, *face, can_go_straight - 1), self.grid[(x, y)] as usize + weight)
                 })
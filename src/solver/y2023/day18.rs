@@ -5,6 +5,7 @@ use derive_more::{Deref, FromStr};
 use itertools::Itertools;
 
 use crate::solver::{combine_solver, ProblemSolver};
+use crate::utils::grid::point::{Point2, Vec2};
 use crate::utils::grid::GridDirection;
 
 combine_solver!(Day18, Day18Part1, Day18Part2);
@@ -52,30 +53,30 @@ impl FromStr for Day18Part1 {
     }
 }
 
+/// Shoelace formula for the area inside the trench, plus the half of the
+/// trench outside of it.
+fn dig_area(plan: &[(GridDirection, isize)]) -> Result<usize> {
+    let (area, perimeter, last) = plan.iter().fold(
+        (0_isize, 0_isize, Point2::default()),
+        |(mut area, mut perimeter, prev), (direction, step)| {
+            let next = prev + Vec2::from(*direction) * *step;
+            area += (prev.y + next.y) * (prev.x - next.x);
+            perimeter += step;
+            (area, perimeter, next)
+        },
+    );
+    if last != Point2::default() {
+        bail!("Last vertex is not the beginning vertex")
+    }
+
+    Ok((area.unsigned_abs() + perimeter as usize) / 2 + 1)
+}
+
 impl ProblemSolver for Day18Part1 {
     type SolutionType = usize;
 
     fn solve(&self) -> Result<Self::SolutionType> {
-        let (area, perimeter, (last_x, last_y)) = self.iter().fold(
-            (0_isize, 0_isize, (0_isize, 0_isize)),
-            |(mut area, mut perimeter, (prev_x, prev_y)), (direction, step)| {
-                let (next_x, next_y) = match direction {
-                    GridDirection::North => (prev_x, prev_y - step),
-                    GridDirection::South => (prev_x, prev_y + step),
-                    GridDirection::East => (prev_x + step, prev_y),
-                    GridDirection::West => (prev_x - step, prev_y),
-                    _ => unreachable!(),
-                };
-                area += (prev_y + next_y) * (prev_x - next_x);
-                perimeter += step;
-                (area, perimeter, (next_x, next_y))
-            },
-        );
-        if last_x != 0 && last_y != 0 {
-            bail!("Last vertex is not the beginning vertex")
-        }
-
-        Ok((area.unsigned_abs() + perimeter as usize) / 2 + 1)
+        dig_area(self)
     }
 }
 
@@ -114,26 +115,7 @@ impl ProblemSolver for Day18Part2 {
     type SolutionType = usize;
 
     fn solve(&self) -> Result<Self::SolutionType> {
-        let (area, perimeter, (last_x, last_y)) = self.iter().fold(
-            (0_isize, 0_isize, (0_isize, 0_isize)),
-            |(mut area, mut perimeter, (prev_x, prev_y)), (direction, step)| {
-                let (next_x, next_y) = match direction {
-                    GridDirection::North => (prev_x, prev_y - step),
-                    GridDirection::South => (prev_x, prev_y + step),
-                    GridDirection::East => (prev_x + step, prev_y),
-                    GridDirection::West => (prev_x - step, prev_y),
-                    _ => unreachable!(),
-                };
-                area += (prev_y + next_y) * (prev_x - next_x);
-                perimeter += step;
-                (area, perimeter, (next_x, next_y))
-            },
-        );
-        if last_x != 0 && last_y != 0 {
-            bail!("Last vertex is not the beginning vertex")
-        }
-
-        Ok((area.unsigned_abs() + perimeter as usize) / 2 + 1)
+        dig_area(self)
     }
 }
 
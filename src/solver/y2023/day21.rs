@@ -4,17 +4,18 @@ use std::rc::Rc;
 
 use anyhow::{anyhow, bail, ensure, Context, Result};
 use bit_set::BitSet;
-use bitvec::vec::BitVec;
 use derive_more::{Deref, FromStr};
 use derive_new::new;
 use itertools::Itertools;
 use num::Integer;
 
-use crate::solver::{share_struct_solver, ProblemSolver};
+use crate::solver::{share_struct_solver, solver_param, ProblemSolver};
 use crate::utils::graph::bfs;
 use crate::utils::grid::grid_2d_bitvec::Grid2dBitVec;
-use crate::utils::grid::{Grid2d, GridDirection};
+use crate::utils::grid::neighbor::Grid2dNeighbor;
+use crate::utils::grid::Grid2d;
 use crate::utils::WarningResult;
+use crate::visualize::{default_color, Frame, FrameSink, Visualize};
 
 share_struct_solver!(Day21, Day21Part1, Day21Part2);
 
@@ -44,23 +45,13 @@ impl FromStr for Day21Part1 {
 
     fn from_str(s: &str) -> Result<Self> {
         let start = OnceCell::default();
-        let start_ref = &start;
-        let iter = s.lines().enumerate().map(|(y, line)| {
-            line.chars().enumerate().map(|(x, c)| (x, parse_position(c))).map(move |(x, res)| {
-                res.and_then(|(blocked, is_start)| {
-                    if is_start {
-                        start_ref
-                            .set((x, y))
-                            .map(|_| blocked)
-                            .map_err(|_| anyhow!("Found 2 starting position"))
-                    } else {
-                        Ok(blocked)
-                    }
-                })
-            })
-        });
-
-        let grid = Grid2dBitVec::try_new(iter)?;
+        let grid = Grid2dBitVec::parse_with(s, |x, y, b| {
+            let (blocked, is_start) = parse_position(b as char)?;
+            if is_start {
+                start.set((x, y)).map_err(|_| anyhow!("Found 2 starting position"))?;
+            }
+            Ok(blocked)
+        })?;
         let start = start.into_inner().context("Cannot find starting pos")?;
 
         Ok(Day21Part1::new(start, grid))
@@ -69,26 +60,16 @@ impl FromStr for Day21Part1 {
 
 impl Display for Day21Part1 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let output = Itertools::intersperse(
-            self.grid.rows().enumerate().map(|(y, line)| {
-                line.iter()
-                    .enumerate()
-                    .map(|(x, blocked)| {
-                        if (x, y) == self.start {
-                            'S'
-                        } else if *blocked {
-                            '#'
-                        } else {
-                            '.'
-                        }
-                    })
-                    .collect::<String>()
-            }),
-            "\n".to_owned(),
-        )
-        .collect::<String>();
-
-        f.write_str(output.as_ref())
+        let display = self.grid.display_with(|x, y, blocked| {
+            if (x, y) == self.start {
+                'S'
+            } else if *blocked {
+                '#'
+            } else {
+                '.'
+            }
+        });
+        write!(f, "{}", display)
     }
 }
 
@@ -96,13 +77,10 @@ impl ProblemSolver for Day21Part1 {
     type SolutionType = usize;
 
     fn solve(&self) -> Result<Self::SolutionType> {
-        Ok(self.step(64).0.len())
+        Ok(self.step(solver_param(2023, 21, "steps", 64)?).0.len())
     }
 }
 
-const CARDINAL: &[GridDirection; 4] =
-    &[GridDirection::North, GridDirection::South, GridDirection::East, GridDirection::West];
-
 impl Day21Part1 {
     fn step(&self, step_count: usize) -> (BitSet<usize>, BitSet<usize>) {
         let step_count_inner = step_count + 1;
@@ -137,35 +115,59 @@ impl Day21Part1 {
     }
 
     fn get_neighbor(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
-        CARDINAL
-            .iter()
-            .filter_map(|direction| {
-                self.grid.move_from_coordinate_to_direction(x, y, 1, *direction)
-            })
-            .filter(|(x, y)| !self.grid[(*x, *y)])
+        self.grid
+            .cardinal_neighbors_where(x, y, |blocked| !blocked)
+            .map(|(x, y, _, _)| (x, y))
             .collect()
     }
 
-    #[allow(dead_code)]
-    fn to_string_with_occupied(&self, occupied: &BitVec) -> String {
-        Itertools::intersperse(
-            self.grid.rows().enumerate().map(|(y, line)| {
-                line.iter()
-                    .enumerate()
-                    .map(|(x, blocked)| {
-                        if occupied[self.grid.flatten_idx(x, y)] {
-                            'O'
-                        } else if *blocked {
-                            '#'
-                        } else {
-                            '.'
-                        }
-                    })
-                    .collect::<String>()
-            }),
-            "\n".to_owned(),
-        )
-        .collect::<String>()
+    fn to_string_with_occupied(&self, occupied: &BitSet<usize>) -> String {
+        self.grid
+            .display_with(|x, y, blocked| {
+                if occupied.contains(self.grid.flatten_idx(x, y)) {
+                    'O'
+                } else if *blocked {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .to_string()
+    }
+}
+
+impl Visualize for Day21Part1 {
+    /// Plots reachable in exactly k steps, for every k up to
+    /// "y2023.day21.steps".
+    fn visualize(&self, sink: &mut dyn FrameSink) -> Result<()> {
+        let steps = solver_param(2023, 21, "steps", 64)?;
+        // Positions first reached at each distance, from a single search.
+        let mut layers = vec![Vec::new(); steps + 1];
+        bfs(
+            self.start,
+            |(x, y)| self.get_neighbor(*x, *y),
+            |depth, (x, y)| {
+                // Depth counts the start as 1.
+                if *depth > steps + 1 {
+                    true
+                } else {
+                    layers[*depth - 1].push(self.grid.flatten_idx(*x, *y));
+                    false
+                }
+            },
+            0_usize,
+            |prev_depth, _| 1 + prev_depth,
+        );
+        // Reachable in exactly k steps is everything at distance k or less
+        // with the same parity as k.
+        let mut occupied = [BitSet::default(), BitSet::default()];
+        for (step_count, layer) in layers.into_iter().enumerate() {
+            let occupied = &mut occupied[step_count % 2];
+            occupied.extend(layer);
+            let text = self.to_string_with_occupied(occupied);
+            sink.push(&Frame::from_text(&text, default_color))?;
+        }
+        Ok(())
     }
 }
 
@@ -173,6 +175,17 @@ impl ProblemSolver for Day21Part2 {
     type SolutionType = WarningResult<usize>;
 
     fn solve(&self) -> Result<Self::SolutionType> {
+        Ok(WarningResult::new(
+            self.count_infinite(26501365)?,
+            "Check code for assumption. Also assume every fillable position within 26501365 euclidean distance is filled.",
+        ))
+    }
+}
+
+impl Day21Part2 {
+    /// Plots reachable in exactly `step_count` steps on the infinitely tiled
+    /// map, from the count of full and corner cut tiles.
+    fn count_infinite(&self, step_count: usize) -> Result<usize> {
         ensure!(
             self.grid.width() == self.grid.height(),
             "Failed to assume provided grid is a square"
@@ -191,10 +204,11 @@ impl ProblemSolver for Day21Part2 {
         );
         ensure!(self.grid[(0.., radius)].not_any(), "Failed to assume middle row of grid is empty");
         ensure!(
-            (26501365 - radius) % grid_edge == 0,
-            "Failed to assume 26501365 step will end next to a grid edge"
+            step_count >= radius && (step_count - radius).is_multiple_of(grid_edge),
+            "Failed to assume {} step will end next to a grid edge",
+            step_count
         );
-        let grid_count_radius = (26501365 - radius) / grid_edge;
+        let grid_count_radius = (step_count - radius) / grid_edge;
         ensure!(grid_count_radius.is_even(), "Failed to grid count radius is even");
 
         let corner_mask: BitSet<usize> = (0..grid_edge)
@@ -221,23 +235,25 @@ impl ProblemSolver for Day21Part2 {
             + grid_count_radius * even_grid_corner_count
             - (grid_count_radius + 1) * odd_grid_corner_count;
 
-        Ok(WarningResult::new(
-            res,
-            "Check code for assumption. Also assume every fillable position within 26501365 euclidean distance is filled.",
-        ))
+        Ok(res)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use std::ops::Deref;
+    use std::rc::Rc;
     use std::str::FromStr;
 
     use anyhow::Result;
     use indoc::indoc;
 
-    use crate::solver::y2023::day21::{Day21, Day21Part1};
+    use crate::solver::y2023::day21::{Day21, Day21Part1, Day21Part2};
     use crate::solver::TwoPartsProblemSolver;
+    use crate::utils::graph::bfs;
+    use crate::utils::grid::point::Point2;
+    use crate::utils::grid::tiled::TiledGrid;
+    use crate::visualize::{VecSink, Visualize};
 
     const SAMPLE_INPUT_1: &str = indoc! {r"
             ...........
@@ -399,4 +415,59 @@ mod tests {
         assert_eq!(*Day21::from_str(SAMPLE_INPUT_2)?.solve_2()?.deref(), 621494544278648);
         Ok(())
     }
+
+    /// Plots reachable in exactly `step_count` steps, searching the tiled map
+    /// directly.
+    fn step_infinite(day: &Day21Part1, step_count: usize) -> usize {
+        let tiled = TiledGrid::new(&day.grid).unwrap();
+        let mut count = 0;
+        bfs(
+            Point2::new(day.start.0 as isize, day.start.1 as isize),
+            |point| {
+                tiled
+                    .cardinal_neighbors(*point)
+                    .filter(|(_, _, blocked)| !**blocked)
+                    .map(|(point, _, _)| point)
+                    .collect::<Vec<_>>()
+            },
+            |depth, _| {
+                if *depth > step_count {
+                    return true;
+                }
+                if depth % 2 == step_count % 2 {
+                    count += 1;
+                }
+                false
+            },
+            usize::MAX,
+            |prev_depth, _| prev_depth.wrapping_add(1),
+        );
+        count
+    }
+
+    #[test]
+    fn test_count_infinite_matches_search() -> Result<()> {
+        let day = Rc::new(Day21Part1::from_str(SAMPLE_INPUT_2)?);
+        let step_count = 65 + 2 * 131;
+        assert_eq!(
+            Day21Part2(day.clone()).count_infinite(step_count)?,
+            step_infinite(&day, step_count)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_visualize() -> Result<()> {
+        let day = Day21Part1::from_str(SAMPLE_INPUT_1)?;
+        let mut sink = VecSink::default();
+        day.visualize(&mut sink)?;
+        assert_eq!(sink.0.len(), 65);
+        let occupied =
+            |idx: usize| sink.0[idx].rows().flatten().filter(|cell| cell.ch == 'O').count();
+        assert_eq!(occupied(0), 1);
+        assert_eq!(sink.0[0].rows().nth(5).unwrap()[5].ch, 'O');
+        assert_eq!(occupied(6), day.step(6).0.len());
+        assert_eq!(occupied(64), day.step(64).0.len());
+        Ok(())
+    }
 }
@@ -1,6 +1,5 @@
 use std::cell::RefCell;
 use std::cmp::Ordering;
-use std::collections::HashMap;
 use std::fmt::Debug;
 use std::iter;
 use std::rc::Rc;
@@ -12,7 +11,10 @@ use dyn_iter::{DynIter, IntoDynIterator};
 use itertools::Itertools;
 
 use crate::solver::{share_struct_solver, ProblemSolver};
+use crate::utils::grid::point::{Point2, Point3};
+use crate::utils::grid::sparse::SparseGrid;
 use crate::utils::int_range::IntRange;
+use crate::visualize::{Cell, Frame, FrameSink, Rgb, Visualize};
 
 share_struct_solver!(Day22, Day22Part1, Day22Part2);
 
@@ -92,23 +94,28 @@ impl Brick {
         }
     }
 
-    fn x_y_iter(&self) -> DynIter<(u16, u16)> {
+    /// Every unit cube of the brick.
+    fn cubes(&self) -> DynIter<'_, Point3<u16>> {
         match self {
-            Brick::XBar(x, y, _) => (x.start..=x.end).map(|x| (x, *y)).into_dyn_iter(),
-            Brick::YBar(x, y, _) => (y.start..=y.end).map(|y| (*x, y)).into_dyn_iter(),
-            Brick::ZBar(x, y, _) => iter::once((*x, *y)).into_dyn_iter(),
-            Brick::Cube(x, y, _) => iter::once((*x, *y)).into_dyn_iter(),
+            Brick::XBar(x, y, z) => {
+                (x.start..=x.end).map(|x| Point3::new(x, *y, *z)).into_dyn_iter()
+            }
+            Brick::YBar(x, y, z) => {
+                (y.start..=y.end).map(|y| Point3::new(*x, y, *z)).into_dyn_iter()
+            }
+            Brick::ZBar(x, y, z) => {
+                (z.start..=z.end).map(|z| Point3::new(*x, *y, z)).into_dyn_iter()
+            }
+            Brick::Cube(x, y, z) => iter::once(Point3::new(*x, *y, *z)).into_dyn_iter(),
         }
     }
 
-    #[allow(dead_code)]
-    fn x_y_z_iter(&self) -> DynIter<(u16, u16, u16)> {
-        match self {
-            Brick::XBar(x, y, z) => (x.start..=x.end).map(|x| (x, *y, *z)).into_dyn_iter(),
-            Brick::YBar(x, y, z) => (y.start..=y.end).map(|y| (*x, y, *z)).into_dyn_iter(),
-            Brick::ZBar(x, y, z) => (z.start..=z.end).map(|z| (*x, *y, z)).into_dyn_iter(),
-            Brick::Cube(x, y, z) => iter::once((*x, *y, *z)).into_dyn_iter(),
-        }
+    /// Cubes of the bottom layer, as seen from above.
+    fn footprint(&self) -> impl Iterator<Item = Point2<isize>> + '_ {
+        let bottom = self.get_bottom();
+        self.cubes()
+            .filter(move |cube| cube.z == bottom)
+            .map(|cube| Point2::new(cube.x as isize, cube.y as isize))
     }
 
     fn get_bottom(&self) -> u16 {
@@ -129,7 +136,6 @@ impl Brick {
         }
     }
 
-    #[allow(dead_code)]
     fn set_height(&self, target_height: u16) -> Self {
         assert!(target_height <= self.get_bottom());
         match self {
@@ -148,43 +154,54 @@ impl Brick {
 #[derive(Deref)]
 pub struct Day22Part2(Rc<Day22Part1>);
 
+/// Bricks sorted by bottom, which is the order they land in.
+fn parse_bricks(s: &str) -> Result<Vec<Brick>> {
+    let mut bricks = s.lines().map(Brick::from_str).collect::<Result<Vec<_>>>()?;
+    bricks.sort_unstable_by_key(|brick| brick.get_bottom());
+    Ok(bricks)
+}
+
+/// Let `bricks` sorted by bottom fall one after another, returns for each
+/// brick the bricks it rests on and the height of its top once landed.
+fn settle(bricks: &[Brick]) -> Vec<(BitSet, BrickHeight)> {
+    let mut height_map = SparseGrid::<Option<(BrickHeight, BrickIdx)>>::new(None);
+    bricks
+        .iter()
+        .enumerate()
+        .map(|(brick_idx, brick)| {
+            let (supports, height) =
+                brick.footprint().filter_map(|point| *height_map.get(point)).fold(
+                    (BitSet::default(), 0_u16),
+                    |(mut supports, curr_max_height), (height, brick_id)| match height
+                        .cmp(&curr_max_height)
+                    {
+                        Ordering::Less => (supports, curr_max_height),
+                        Ordering::Equal => {
+                            supports.insert(brick_id);
+                            (supports, curr_max_height)
+                        }
+                        Ordering::Greater => {
+                            supports.clear();
+                            supports.insert(brick_id);
+                            (supports, height)
+                        }
+                    },
+                );
+            let height = height + brick.get_height();
+            brick.footprint().for_each(|point| {
+                height_map.set(point, Some((height, brick_idx)));
+            });
+            (supports, height)
+        })
+        .collect_vec()
+}
+
 impl FromStr for Day22Part1 {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let mut bricks = s.lines().map(Brick::from_str).collect::<Result<Vec<_>>>()?;
-        bricks.sort_unstable_by_key(|brick| brick.get_bottom());
-
-        let mut height_map = HashMap::<(u16, u16), (BrickHeight, BrickIdx)>::default();
-        let brick_supported_by = bricks
-            .into_iter()
-            .enumerate()
-            .map(|(brick_idx, brick)| {
-                let (supports, height) =
-                    brick.x_y_iter().filter_map(|(x, y)| height_map.get(&(x, y))).fold(
-                        (BitSet::default(), 0_u16),
-                        |(mut supports, curr_max_height), (height, brick_id)| match height
-                            .cmp(&curr_max_height)
-                        {
-                            Ordering::Less => (supports, curr_max_height),
-                            Ordering::Equal => {
-                                supports.insert(*brick_id);
-                                (supports, curr_max_height)
-                            }
-                            Ordering::Greater => {
-                                supports.clear();
-                                supports.insert(*brick_id);
-                                (supports, *height)
-                            }
-                        },
-                    );
-                let height = height + brick.get_height();
-                brick.x_y_iter().for_each(|xy| {
-                    height_map.insert(xy, (height, brick_idx));
-                });
-                supports
-            })
-            .collect_vec();
+        let brick_supported_by =
+            settle(&parse_bricks(s)?).into_iter().map(|(supports, _)| supports).collect_vec();
         let mut brick_supporting = vec![BitSet::default(); brick_supported_by.len()];
         brick_supported_by.iter().enumerate().for_each(|(brick_id, supported_by_ids)| {
             supported_by_ids.iter().for_each(|supporter_id| {
@@ -210,6 +227,51 @@ impl ProblemSolver for Day22Part1 {
     }
 }
 
+/// Input bricks as given, only used to draw them falling.
+pub struct Day22Bricks(Vec<Brick>);
+
+impl FromStr for Day22Bricks {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Day22Bricks(parse_bricks(s)?))
+    }
+}
+
+impl Visualize for Day22Bricks {
+    /// Bricks seen from the side (x right, z up), one frame per landed
+    /// brick. Landed bricks are lit, falling ones are dim.
+    fn visualize(&self, sink: &mut dyn FrameSink) -> Result<()> {
+        let settled = settle(&self.0)
+            .into_iter()
+            .zip(&self.0)
+            .map(|((_, top), brick)| brick.set_height(top + 1 - brick.get_height()))
+            .collect_vec();
+        let cubes = || self.0.iter().flat_map(|brick| brick.cubes());
+        let width = cubes().map(|cube| cube.x as usize + 1).max().unwrap_or(0);
+        let height = cubes().map(|cube| cube.z as usize + 1).max().unwrap_or(0);
+        let empty = Cell { ch: '.', color: Rgb(40, 40, 40) };
+        for landed in 0..=self.0.len() {
+            let mut frame = Frame::new(width, height, empty);
+            let ground = Cell { ch: '-', color: Rgb(200, 200, 200) };
+            (0..width).for_each(|x| frame.set(x, height - 1, ground));
+            self.0.iter().zip(&settled).enumerate().for_each(|(idx, (brick, settled))| {
+                let (brick, color) = if idx < landed {
+                    (settled, Rgb(255, 200, 0))
+                } else {
+                    (brick, Rgb(90, 90, 90))
+                };
+                let ch = char::from(b'A' + (idx % 26) as u8);
+                brick.cubes().for_each(|cube| {
+                    frame.set(cube.x as usize, height - 1 - cube.z as usize, Cell { ch, color })
+                });
+            });
+            sink.push(&frame)?;
+        }
+        Ok(())
+    }
+}
+
 impl ProblemSolver for Day22Part2 {
     type SolutionType = usize;
 
@@ -266,8 +328,9 @@ mod tests {
     use anyhow::Result;
     use indoc::indoc;
 
-    use crate::solver::y2023::day22::Day22;
+    use crate::solver::y2023::day22::{Day22, Day22Bricks};
     use crate::solver::TwoPartsProblemSolver;
+    use crate::visualize::{VecSink, Visualize};
 
     const SAMPLE_INPUT_1: &str = indoc! {r"
             1,0,1~1,2,1
@@ -290,4 +353,21 @@ mod tests {
         assert_eq!(Day22::from_str(SAMPLE_INPUT_1)?.solve_2()?, 7);
         Ok(())
     }
+
+    #[test]
+    fn test_visualize() -> Result<()> {
+        let mut sink = VecSink::default();
+        Day22Bricks::from_str(SAMPLE_INPUT_1)?.visualize(&mut sink)?;
+        assert_eq!(sink.0.len(), 8);
+        // Rows go from z = 9 at the top down to the ground at z = 0.
+        let row = |idx: usize, z: usize| {
+            sink.0[idx].rows().nth(9 - z).unwrap().iter().map(|c| c.ch).collect::<String>()
+        };
+        assert_eq!(row(0, 0), "---");
+        assert_eq!(row(0, 1), ".A.");
+        assert_eq!(row(0, 9), ".G.");
+        assert_eq!(row(7, 6), ".G.");
+        assert_eq!(row(7, 9), "...");
+        Ok(())
+    }
 }
@@ -8,7 +8,7 @@ use derive_more::{Add, AddAssign, Deref, From, FromStr, Into, Sub, SubAssign};
 use derive_new::new;
 use itertools::Itertools;
 
-use crate::solver::{share_struct_solver, ProblemSolver};
+use crate::solver::{share_struct_solver, solver_param, ProblemSolver};
 
 share_struct_solver!(Day24, Day24Part1, Day24Part2);
 
@@ -36,6 +36,7 @@ type Velocity = Vec3D;
 type Position2D = Vec2D;
 type Velocity2D = Vec2D;
 
+/// Stays on `f64`, [`crate::utils::grid::point`] types only hold integers.
 #[derive(Copy, Clone, From, Into, Debug)]
 struct Vec3D {
     x: f64,
@@ -181,7 +182,9 @@ impl ProblemSolver for Day24Part1 {
     type SolutionType = usize;
 
     fn solve(&self) -> Result<Self::SolutionType> {
-        Ok(self.intersect_in_area_count(&(200000000000000.0..=400000000000000.0)))
+        let area_min = solver_param(2023, 24, "area_min", 200000000000000.0)?;
+        let area_max = solver_param(2023, 24, "area_max", 400000000000000.0)?;
+        Ok(self.intersect_in_area_count(&(area_min..=area_max)))
     }
 }
 
@@ -1,24 +1,20 @@
-use std::fmt::Display;
-use std::path::Path;
-
-use anyhow::Result;
-use phf::{phf_map, Map};
+use phf::phf_map;
 
 use crate::solver::y2023::day1::Day1;
-use crate::solver::y2023::day10::Day10;
+use crate::solver::y2023::day10::{Day10, Day10Part1};
 use crate::solver::y2023::day11::Day11;
 use crate::solver::y2023::day12::Day12;
 use crate::solver::y2023::day13::Day13;
-use crate::solver::y2023::day14::Day14;
+use crate::solver::y2023::day14::{Day14, Day14Part1};
 use crate::solver::y2023::day15::Day15;
-use crate::solver::y2023::day16::Day16;
+use crate::solver::y2023::day16::{Day16, Day16Part1};
 use crate::solver::y2023::day17::Day17;
 use crate::solver::y2023::day18::Day18;
 use crate::solver::y2023::day19::Day19;
 use crate::solver::y2023::day2::Day2;
 use crate::solver::y2023::day20::Day20;
-use crate::solver::y2023::day21::Day21;
-use crate::solver::y2023::day22::Day22;
+use crate::solver::y2023::day21::{Day21, Day21Part1};
+use crate::solver::y2023::day22::{Day22, Day22Bricks};
 use crate::solver::y2023::day23::Day23;
 use crate::solver::y2023::day24::Day24;
 use crate::solver::y2023::day25::Day25;
@@ -29,7 +25,8 @@ use crate::solver::y2023::day6::Day6;
 use crate::solver::y2023::day7::Day7;
 use crate::solver::y2023::day8::Day8;
 use crate::solver::y2023::day9::Day9;
-use crate::utils::boxed_try_get_input_and_solve;
+use crate::solver::{DaySolverMap, DayVisualizerMap};
+use crate::utils::{boxed_try_get_input_and_solve, parse_and_visualize};
 
 pub mod day1;
 pub mod day10;
@@ -57,7 +54,7 @@ pub mod day7;
 pub mod day8;
 pub mod day9;
 
-pub const Y2023_SOLVER: Map<u8, fn(u16, u8, &Path, &Path) -> Result<Box<dyn Display>>> = phf_map! {
+pub const Y2023_SOLVER: DaySolverMap = phf_map! {
     1_u8 => boxed_try_get_input_and_solve!(Day1),
     2_u8 => boxed_try_get_input_and_solve!(Day2),
     3_u8 => boxed_try_get_input_and_solve!(Day3),
@@ -84,3 +81,11 @@ pub const Y2023_SOLVER: Map<u8, fn(u16, u8, &Path, &Path) -> Result<Box<dyn Disp
     24_u8 => boxed_try_get_input_and_solve!(Day24),
     25_u8 => boxed_try_get_input_and_solve!(Day25),
 };
+
+pub const Y2023_VISUALIZER: DayVisualizerMap = phf_map! {
+    10_u8 => parse_and_visualize!(Day10Part1),
+    14_u8 => parse_and_visualize!(Day14Part1),
+    16_u8 => parse_and_visualize!(Day16Part1),
+    21_u8 => parse_and_visualize!(Day21Part1),
+    22_u8 => parse_and_visualize!(Day22Bricks),
+};
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::input::answers::{has_assumption, read_answer};
+use crate::input::fs::FsInputProvider;
+use crate::solver::DaySolverMap;
+
+/// What is known about one puzzle, shown by the `status` command.
+#[derive(Default, Debug)]
+pub struct DayStatus {
+    pub implemented: bool,
+    pub input_cached: bool,
+    /// Recorded answer, see [`crate::input::answers`].
+    pub answer: Option<String>,
+    /// Stars from the event calendar, `None` if it was not fetched.
+    pub stars: Option<u8>,
+}
+
+impl DayStatus {
+    pub fn verified(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    pub fn has_assumption(&self) -> bool {
+        self.answer.as_deref().is_some_and(has_assumption)
+    }
+}
+
+pub fn day_statuses(
+    year: u16,
+    solvers: Option<&DaySolverMap>,
+    input_providers: &[FsInputProvider],
+    answer_path: &Path,
+    stars: Option<&HashMap<u8, u8>>,
+) -> Result<Vec<DayStatus>> {
+    (1..=25)
+        .map(|day| {
+            Ok(DayStatus {
+                implemented: solvers.is_some_and(|solvers| solvers.contains_key(&day)),
+                input_cached: input_providers.iter().any(|p| p.has_input(year, day)),
+                answer: read_answer(answer_path, year, day)?,
+                stars: stars.map(|stars| stars.get(&day).copied().unwrap_or(0)),
+            })
+        })
+        .collect()
+}
+
+/// One column per day: "#" yes, "." no, "!" needs attention (solved without
+/// input, or built on an unverified assumption).
+pub fn render_status(year: u16, statuses: &[DayStatus]) -> Result<String> {
+    let mut out = String::new();
+    let row = |out: &mut String, name: &str, cell: &dyn Fn(&DayStatus) -> &'static str| {
+        write!(out, "  {:<12}", name)?;
+        statuses.iter().try_for_each(|status| write!(out, "{:>3}", cell(status)))?;
+        writeln!(out)
+    };
+    write!(out, "{:<14}", year)?;
+    (1..=statuses.len()).try_for_each(|day| write!(out, "{:>3}", day))?;
+    writeln!(out)?;
+    row(&mut out, "solver", &|s| if s.implemented { "#" } else { "." })?;
+    row(&mut out, "input", &|s| match (s.input_cached, s.implemented) {
+        (true, _) => "#",
+        (false, true) => "!",
+        (false, false) => ".",
+    })?;
+    row(&mut out, "verified", &|s| if s.verified() { "#" } else { "." })?;
+    row(&mut out, "assumption", &|s| if s.has_assumption() { "!" } else { "." })?;
+    if statuses.iter().any(|s| s.stars.is_some()) {
+        row(&mut out, "stars", &|s| match s.stars {
+            Some(2) => "**",
+            Some(1) => "*",
+            _ => ".",
+        })?;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use indoc::indoc;
+
+    use crate::status::{render_status, DayStatus};
+
+    #[test]
+    fn test_render_status() -> Result<()> {
+        let statuses = vec![
+            DayStatus {
+                implemented: true,
+                input_cached: true,
+                answer: Some("<part 1: 1, part 2: 2>".to_owned()),
+                stars: Some(2),
+            },
+            DayStatus {
+                implemented: true,
+                input_cached: true,
+                answer: Some("<part 1: 1, part 2: 2 --Assuming cycle-->".to_owned()),
+                stars: Some(1),
+            },
+            DayStatus { implemented: true, stars: Some(0), ..DayStatus::default() },
+            DayStatus { stars: Some(0), ..DayStatus::default() },
+        ];
+        assert_eq!(
+            render_status(2021, &statuses)?,
+            indoc! {"
+                2021            1  2  3  4
+                  solver        #  #  #  .
+                  input         #  #  !  .
+                  verified      #  #  .  .
+                  assumption    .  !  .  .
+                  stars        **  *  .  .
+            "}
+        );
+        Ok(())
+    }
+}
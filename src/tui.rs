@@ -0,0 +1,426 @@
+use std::collections::HashMap;
+use std::io::stdout;
+use std::panic::{
+    catch_unwind, resume_unwind, set_hook, take_hook, AssertUnwindSafe, PanicHookInfo,
+};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::{event, execute};
+use ratatui::backend::{Backend, CrosstermBackend};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{Frame, Terminal};
+
+use crate::input::answers::has_assumption;
+use crate::input::chained::ChainedInputProvider;
+use crate::input::InputProvider;
+use crate::solver::{get_year_solver, SolverFn, AOC_PROBLEMS_SOLVER};
+
+type Puzzle = (u16, u8);
+
+#[derive(Clone, Debug)]
+pub enum RunState {
+    Queued,
+    Running(Instant),
+    Done { result: String, duration: Duration },
+    Failed { error: String, duration: Duration },
+}
+
+/// Message from the solver thread.
+#[derive(Debug)]
+pub enum RunEvent {
+    Started(Puzzle, Instant),
+    Finished(Puzzle, Result<String, String>, Duration),
+}
+
+/// Dashboard state, separate from terminal so it can be driven in tests.
+pub struct App {
+    puzzles: Vec<Puzzle>,
+    list_state: ListState,
+    runs: HashMap<Puzzle, RunState>,
+    inputs: HashMap<Puzzle, Option<String>>,
+    input_scroll: u16,
+    quit: bool,
+}
+
+impl App {
+    pub fn new() -> Self {
+        let puzzles = AOC_PROBLEMS_SOLVER
+            .iter()
+            .flat_map(|(year, solvers)| {
+                let mut days = solvers.keys().copied().collect::<Vec<_>>();
+                days.sort();
+                days.into_iter().map(move |day| (*year, day))
+            })
+            .collect::<Vec<_>>();
+        let mut list_state = ListState::default();
+        if !puzzles.is_empty() {
+            list_state.select(Some(0));
+        }
+        Self {
+            puzzles,
+            list_state,
+            runs: HashMap::default(),
+            inputs: HashMap::default(),
+            input_scroll: 0,
+            quit: false,
+        }
+    }
+
+    pub fn selected(&self) -> Option<Puzzle> {
+        self.list_state.selected().map(|idx| self.puzzles[idx])
+    }
+
+    fn select(&mut self, offset: isize) {
+        if let Some(idx) = self.list_state.selected() {
+            let idx = idx.saturating_add_signed(offset).min(self.puzzles.len() - 1);
+            self.list_state.select(Some(idx));
+            self.input_scroll = 0;
+        }
+    }
+
+    /// Puzzles to run for a key press.
+    pub fn handle_key(&mut self, key: KeyCode) -> Vec<Puzzle> {
+        let mut to_run = Vec::new();
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Down | KeyCode::Char('j') => self.select(1),
+            KeyCode::Up | KeyCode::Char('k') => self.select(-1),
+            KeyCode::PageDown => self.input_scroll = self.input_scroll.saturating_add(20),
+            KeyCode::PageUp => self.input_scroll = self.input_scroll.saturating_sub(20),
+            KeyCode::Enter | KeyCode::Char('r') => to_run.extend(self.selected()),
+            KeyCode::Char('a') => {
+                if let Some((year, _)) = self.selected() {
+                    to_run.extend(self.puzzles.iter().filter(|(y, _)| *y == year));
+                }
+            }
+            _ => {}
+        }
+        to_run.iter().for_each(|puzzle| {
+            self.runs.insert(*puzzle, RunState::Queued);
+        });
+        to_run
+    }
+
+    pub fn handle_run_event(&mut self, event: RunEvent) {
+        match event {
+            RunEvent::Started(puzzle, start) => {
+                self.runs.insert(puzzle, RunState::Running(start));
+            }
+            RunEvent::Finished(puzzle, Ok(result), duration) => {
+                self.runs.insert(puzzle, RunState::Done { result, duration });
+            }
+            RunEvent::Finished(puzzle, Err(error), duration) => {
+                self.runs.insert(puzzle, RunState::Failed { error, duration });
+            }
+        }
+    }
+
+    fn list_item(&self, (year, day): Puzzle) -> ListItem<'static> {
+        let (status, style) = match self.runs.get(&(year, day)) {
+            None => (String::new(), Style::default()),
+            Some(RunState::Queued) => ("queued".to_owned(), Style::default().fg(Color::DarkGray)),
+            Some(RunState::Running(start)) => {
+                (format!("{:.1?}", start.elapsed()), Style::default().fg(Color::Yellow))
+            }
+            Some(RunState::Done { result, duration }) => {
+                let color = if has_assumption(result) { Color::Magenta } else { Color::Green };
+                (format!("{:.1?}", duration), Style::default().fg(color))
+            }
+            Some(RunState::Failed { duration, .. }) => {
+                (format!("{:.1?}", duration), Style::default().fg(Color::Red))
+            }
+        };
+        ListItem::new(format!("{} day {:>2}  {}", year, day, status)).style(style)
+    }
+
+    fn result_text(&self, puzzle: Puzzle) -> String {
+        match self.runs.get(&puzzle) {
+            None => "Not run yet, press enter to run, \"a\" to run the whole year.".to_owned(),
+            Some(RunState::Queued) => "Queued.".to_owned(),
+            Some(RunState::Running(start)) => format!("Running for {:.1?}...", start.elapsed()),
+            Some(RunState::Done { result, duration }) => {
+                let mut text = format!("Result:\n  {}\n\nRuntime: {:?}", result, duration);
+                if has_assumption(result) {
+                    text.push_str("\n\nWarning: result relies on an unverified assumption.");
+                }
+                text
+            }
+            Some(RunState::Failed { error, duration }) => {
+                format!("Error:\n  {}\n\nRuntime: {:?}", error, duration)
+            }
+        }
+    }
+
+    fn draw(&mut self, f: &mut Frame, input: Option<&str>) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(28), Constraint::Min(0)])
+            .split(f.size());
+        let panes = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(10), Constraint::Min(0)])
+            .split(columns[1]);
+
+        let items = self.puzzles.iter().map(|puzzle| self.list_item(*puzzle)).collect::<Vec<_>>();
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title("Puzzles"))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        f.render_stateful_widget(list, columns[0], &mut self.list_state);
+
+        let Some(puzzle) = self.selected() else {
+            return;
+        };
+        let title = format!("{} day {} (q quit, enter run, a run year)", puzzle.0, puzzle.1);
+        let result = Paragraph::new(self.result_text(puzzle))
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .wrap(Wrap { trim: false });
+        f.render_widget(result, panes[0]);
+
+        let input = Paragraph::new(input.unwrap_or("No cached input."))
+            .block(Block::default().borders(Borders::ALL).title("Input (page up/down)"))
+            .scroll((self.input_scroll, 0));
+        f.render_widget(input, panes[1]);
+    }
+}
+
+/// Solve puzzles one at a time, in order of request. A panicking solver is
+/// reported as failed so the worker keeps serving later jobs.
+fn spawn_worker<F, L>(provider_factory: F, solver_of: L, events: Sender<RunEvent>) -> Sender<Puzzle>
+where
+    F: Fn() -> Result<ChainedInputProvider> + Send + 'static,
+    L: Fn(Puzzle) -> Option<SolverFn> + Send + 'static,
+{
+    let (jobs, job_receiver) = channel::<Puzzle>();
+    thread::spawn(move || {
+        let input_provider = provider_factory();
+        for (year, day) in job_receiver {
+            let start = Instant::now();
+            let _ = events.send(RunEvent::Started((year, day), start));
+            let result = match (&input_provider, solver_of((year, day))) {
+                (Ok(input_provider), Some(solve)) => {
+                    match catch_unwind(AssertUnwindSafe(|| solve(year, day, input_provider))) {
+                        Ok(result) => {
+                            result.map(|result| result.to_string()).map_err(|e| format!("{:#}", e))
+                        }
+                        Err(payload) => Err(panic_message(payload.as_ref())),
+                    }
+                }
+                (Err(e), _) => Err(format!("{:#}", e)),
+                (_, None) => Err("There is no solver for this day".to_owned()),
+            };
+            let _ = events.send(RunEvent::Finished((year, day), result, start.elapsed()));
+        }
+    });
+    jobs
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic");
+    format!("Solver panicked: {}", message)
+}
+
+/// Interactive dashboard, only local input is used so it works offline.
+pub fn run<F>(provider_factory: F) -> Result<()>
+where
+    F: Fn() -> Result<ChainedInputProvider> + Send + 'static,
+{
+    let input_provider = provider_factory()?;
+    let (event_sender, event_receiver) = channel();
+    let solver_of = |(year, day): Puzzle| {
+        get_year_solver(year).and_then(|solvers| solvers.get(&day)).map(|solver| solver.solve)
+    };
+    let jobs = spawn_worker(provider_factory, solver_of, event_sender);
+
+    let panic_capture = PanicCapture::install();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let _terminal_guard = TerminalGuard::enter()?;
+        let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+        event_loop(&mut terminal, &input_provider, &jobs, &event_receiver)
+    }));
+    // Terminal is restored by now, even on panic, so messages can be printed.
+    let messages = panic_capture.restore();
+    result.unwrap_or_else(|payload| {
+        messages.iter().for_each(|message| eprintln!("{}", message));
+        resume_unwind(payload)
+    })
+}
+
+/// Raw mode and alternate screen, left on drop so a panic does not leave the
+/// terminal unusable.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> Result<Self> {
+        enable_raw_mode()?;
+        // Guard exists before entering the alternate screen so a failure
+        // there still disables raw mode.
+        let guard = TerminalGuard;
+        execute!(stdout(), EnterAlternateScreen)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(stdout(), LeaveAlternateScreen);
+    }
+}
+
+type PanicHook = Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+/// Panic hook of the dashboard session, messages are kept instead of being
+/// printed over the alternate screen. Solver panics already show as failed
+/// runs.
+struct PanicCapture {
+    previous: PanicHook,
+    messages: Arc<Mutex<Vec<String>>>,
+}
+
+impl PanicCapture {
+    fn install() -> Self {
+        let messages = Arc::<Mutex<Vec<String>>>::default();
+        let captured = messages.clone();
+        let previous = take_hook();
+        set_hook(Box::new(move |info| {
+            if let Ok(mut captured) = captured.lock() {
+                captured.push(info.to_string());
+            }
+        }));
+        Self { previous, messages }
+    }
+
+    /// Put the previous hook back, returns the captured messages.
+    fn restore(self) -> Vec<String> {
+        set_hook(self.previous);
+        let messages = self.messages.lock().map(|mut messages| std::mem::take(&mut *messages));
+        messages.unwrap_or_default()
+    }
+}
+
+fn event_loop<B: Backend>(
+    terminal: &mut Terminal<B>,
+    input_provider: &dyn InputProvider,
+    jobs: &Sender<Puzzle>,
+    run_events: &Receiver<RunEvent>,
+) -> Result<()> {
+    let mut app = App::new();
+    while !app.quit {
+        run_events.try_iter().for_each(|event| app.handle_run_event(event));
+        let selected = app.selected();
+        if let Some((year, day)) = selected.filter(|puzzle| !app.inputs.contains_key(puzzle)) {
+            let input = input_provider.get_input(year, day).unwrap_or_else(|e| Some(e.to_string()));
+            app.inputs.insert((year, day), input);
+        }
+        let input = selected.and_then(|puzzle| app.inputs.get(&puzzle).cloned().flatten());
+        terminal.draw(|f| app.draw(f, input.as_deref()))?;
+
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(KeyEvent { code, kind: KeyEventKind::Press, .. }) = event::read()? {
+                app.handle_key(code).into_iter().try_for_each(|puzzle| jobs.send(puzzle))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    use anyhow::Result;
+    use crossterm::event::KeyCode;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    use crate::input::chained::ChainedInputProvider;
+    use crate::input::InputProvider;
+    use crate::solver::SolverFn;
+    use crate::tui::{spawn_worker, App, RunEvent};
+
+    /// Needs at least 2 puzzles in the registry to move the selection.
+    #[cfg(any(feature = "y2021", feature = "y2023"))]
+    #[test]
+    fn test_keys_and_render() -> Result<()> {
+        let mut app = App::new();
+        let first = app.selected().unwrap();
+        assert_eq!(app.handle_key(KeyCode::Enter), vec![first]);
+        app.handle_key(KeyCode::Down);
+        assert_ne!(app.selected(), Some(first));
+        app.handle_key(KeyCode::Up);
+        assert!(app.handle_key(KeyCode::Char('a')).iter().all(|(year, _)| *year == first.0));
+
+        app.handle_run_event(RunEvent::Finished(
+            first,
+            Ok("42 --Assuming cycle--".to_owned()),
+            Duration::from_millis(3),
+        ));
+        let mut terminal = Terminal::new(TestBackend::new(100, 20))?;
+        terminal.draw(|f| app.draw(f, Some("1abc2")))?;
+        let screen = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>();
+        assert!(screen.contains("42 --Assuming cycle--"));
+        assert!(screen.contains("unverified assumption"));
+        assert!(screen.contains("1abc2"));
+
+        app.handle_key(KeyCode::Char('q'));
+        assert!(app.quit);
+        Ok(())
+    }
+
+    fn panicking_solver(_: u16, _: u8, _: &dyn InputProvider) -> Result<Box<dyn Display>> {
+        panic!("boom")
+    }
+
+    fn answer_solver(_: u16, day: u8, _: &dyn InputProvider) -> Result<Box<dyn Display>> {
+        Ok(Box::new(day))
+    }
+
+    #[test]
+    fn test_worker_survives_panicking_solver() {
+        let (event_sender, event_receiver) = channel();
+        let solver_of = |(_, day): (u16, u8)| -> Option<SolverFn> {
+            Some(if day == 1 { panicking_solver } else { answer_solver })
+        };
+        let jobs =
+            spawn_worker(|| Ok(ChainedInputProvider::new(Vec::new())), solver_of, event_sender);
+        jobs.send((2023, 1)).unwrap();
+        jobs.send((2023, 2)).unwrap();
+        let finished = event_receiver
+            .iter()
+            .filter_map(|event| match event {
+                RunEvent::Finished(puzzle, result, _) => Some((puzzle, result)),
+                RunEvent::Started(..) => None,
+            })
+            .take(2)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            finished,
+            vec![
+                ((2023, 1), Err("Solver panicked: boom".to_owned())),
+                ((2023, 2), Ok("2".to_owned()))
+            ]
+        );
+    }
+}
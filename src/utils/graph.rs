@@ -5,6 +5,8 @@ use std::hash::Hash;
 use std::iter;
 use std::ops::ControlFlow;
 
+use tracing::{trace, trace_span};
+
 #[derive(Debug)]
 pub struct StateWithWeight<A, S, W: Ord> {
     accumulator: A,
@@ -115,13 +117,19 @@ where
     C: FnMut(&A, &S) -> ControlFlow<R, I>,
     I: IntoIterator<Item = (A, S)>,
 {
+    let _span = trace_span!("dfs").entered();
+    let mut expanded = 0_usize;
+    let mut max_len = work_stack.len();
     while let Some((acc, current_state)) = work_stack.pop() {
         if !visited.contains(&current_state) {
+            expanded += 1;
             match compute_neighbor_fn(&acc, &current_state) {
                 ControlFlow::Continue(iter) => {
-                    iter.into_iter().for_each(|item| work_stack.push(item))
+                    iter.into_iter().for_each(|item| work_stack.push(item));
+                    max_len = max_len.max(work_stack.len());
                 }
                 ControlFlow::Break(b) => {
+                    trace!(expanded, max_len, "dfs reached end state");
                     return ControlFlow::Break((b, current_state, work_stack, visited));
                 }
             }
@@ -130,6 +138,7 @@ where
         }
     }
 
+    trace!(expanded, max_len, "dfs exhausted");
     ControlFlow::Continue(visited)
 }
 
@@ -197,13 +206,19 @@ where
     C: FnMut(&A, &S) -> ControlFlow<R, I>,
     I: IntoIterator<Item = (A, S)>,
 {
+    let _span = trace_span!("bfs").entered();
+    let mut expanded = 0_usize;
+    let mut max_len = work_queue.len();
     while let Some((acc, current_state)) = work_queue.pop_front() {
         if !visited.contains(&current_state) {
+            expanded += 1;
             match compute_neighbor_fn(&acc, &current_state) {
                 ControlFlow::Continue(iter) => {
-                    iter.into_iter().for_each(|item| work_queue.push_back(item))
+                    iter.into_iter().for_each(|item| work_queue.push_back(item));
+                    max_len = max_len.max(work_queue.len());
                 }
                 ControlFlow::Break(b) => {
+                    trace!(expanded, max_len, "bfs reached end state");
                     return ControlFlow::Break((b, current_state, work_queue, visited));
                 }
             }
@@ -212,6 +227,7 @@ where
         }
     }
 
+    trace!(expanded, max_len, "bfs exhausted");
     ControlFlow::Continue(visited)
 }
 
@@ -302,12 +318,17 @@ where
     I: IntoIterator<Item = (S, W)>,
     AF: FnMut(&A, &S, &W) -> A,
 {
+    let _span = trace_span!("dijkstra").entered();
+    let mut expanded = 0_usize;
+    let mut max_len = work_heap.len();
     while let Some(Reverse(state_with_weight)) = work_heap.pop() {
         let (acc, current_state, current_weight) = state_with_weight.into();
         if !visited.contains(&current_state) {
+            expanded += 1;
             let acc = acc_fn(&acc, &current_state, &current_weight);
 
             if end_state_fn(&acc, &current_state, &current_weight) {
+                trace!(expanded, max_len, "dijkstra reached end state");
                 return Some((acc, current_state, current_weight));
             }
             neighbor_fn(&current_state, &current_weight)
@@ -318,10 +339,12 @@ where
                     weight: next_weight,
                 })
                 .for_each(|item| work_heap.push(Reverse(item)));
+            max_len = max_len.max(work_heap.len());
 
             visited.insert(current_state);
         }
     }
 
+    trace!(expanded, max_len, "dijkstra exhausted");
     None
 }
@@ -1,6 +1,6 @@
 use std::cell::OnceCell;
 use std::collections::Bound;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
 use std::ops::{
     Index, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
 };
@@ -14,12 +14,12 @@ use bitvec::store::BitStore;
 use bitvec::vec::BitVec;
 use thiserror::Error;
 
-use crate::utils::grid::Grid2d;
+use crate::utils::grid::{Grid2d, Grid2dMut};
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error("Widths are not the same")]
-    InvalidWidth,
+    #[error("Row {row} (count from 0) has width {width}, expected {expected}")]
+    InvalidWidth { row: usize, width: usize, expected: usize },
 }
 
 #[derive(Debug)]
@@ -108,6 +108,62 @@ impl<S: BitStore, O: BitOrder> Grid2d<bool> for Grid2dBitVec<S, O> {
     }
 }
 
+/// True cells as '#', false as '.'.
+impl<S: BitStore, O: BitOrder> Display for Grid2dBitVec<S, O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_with(|_, _, &b| if b { '#' } else { '.' }))
+    }
+}
+
+/// Writes go to the row major bits and, once it has been built, to the column
+/// major cache too.
+impl<S: BitStore, O: BitOrder> Grid2dMut<bool> for Grid2dBitVec<S, O> {
+    fn set(&mut self, x: usize, y: usize, value: bool) -> bool {
+        let idx = self.flatten_idx(x, y);
+        let idx_x_significant = self.flatten_idx_x_significant(x, y);
+        if let Some(grid_x_significant) = self.grid_x_significant.get_mut() {
+            grid_x_significant.set(idx_x_significant, value);
+        }
+        self.grid.replace(idx, value)
+    }
+
+    fn swap(&mut self, (x1, y1): (usize, usize), (x2, y2): (usize, usize)) {
+        let (idx_1, idx_2) = (self.flatten_idx(x1, y1), self.flatten_idx(x2, y2));
+        let (idx_x_significant_1, idx_x_significant_2) =
+            (self.flatten_idx_x_significant(x1, y1), self.flatten_idx_x_significant(x2, y2));
+        if let Some(grid_x_significant) = self.grid_x_significant.get_mut() {
+            grid_x_significant.swap(idx_x_significant_1, idx_x_significant_2);
+        }
+        self.grid.swap(idx_1, idx_2);
+    }
+
+    fn fill(&mut self, value: bool) {
+        self.grid.fill(value);
+        if let Some(grid_x_significant) = self.grid_x_significant.get_mut() {
+            grid_x_significant.fill(value);
+        }
+    }
+
+    fn set_row<I: IntoIterator<Item = bool>>(&mut self, y: usize, values: I) {
+        assert!(y < self.height);
+        let start_idx = y * self.width;
+        self.grid[start_idx..start_idx + self.width]
+            .iter_mut()
+            .zip(values)
+            .for_each(|(mut bit, value)| *bit = value);
+        self.grid_x_significant.take();
+    }
+
+    fn map_in_place<F: FnMut(usize, usize, &bool) -> bool>(&mut self, mut map_fn: F) {
+        let width = self.width;
+        self.grid.iter_mut().enumerate().for_each(|(idx, mut bit)| {
+            let value = map_fn(idx % width, idx / width, &bit);
+            *bit = value;
+        });
+        self.grid_x_significant.take();
+    }
+}
+
 impl<S: BitStore, O: BitOrder> Grid2dBitVec<S, O> {
     pub fn try_new<I: IntoIterator<Item = Result<bool>>, II: IntoIterator<Item = I>>(
         into_iter: II,
@@ -118,12 +174,14 @@ impl<S: BitStore, O: BitOrder> Grid2dBitVec<S, O> {
             .into_iter()
             .map(|i| i.into_iter())
             .map(Iterator::collect::<Result<BitVec<S, O>>>)
-            .map(|line_res| {
+            .enumerate()
+            .map(|(row, line_res)| {
                 let line = line_res?;
-                if line.len() == *predict_width.get_or_init(|| line.len()) {
+                let &expected = predict_width.get_or_init(|| line.len());
+                if line.len() == expected {
                     Ok(line)
                 } else {
-                    Err(Error::InvalidWidth)?
+                    Err(Error::InvalidWidth { row, width: line.len(), expected })?
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -141,6 +199,45 @@ impl<S: BitStore, O: BitOrder> Grid2dBitVec<S, O> {
         Ok(Self { grid, grid_x_significant: OnceCell::default(), height, width })
     }
 
+    /// One cell per byte of each line, `parse_fn` gets the cell coordinate so
+    /// it can record special cells such as a start.
+    pub fn parse_with<F: FnMut(usize, usize, u8) -> Result<bool>>(
+        s: &str,
+        mut parse_fn: F,
+    ) -> Result<Self> {
+        let mut rows = Vec::new();
+        for (y, line) in s.lines().enumerate() {
+            rows.push(
+                line.bytes()
+                    .enumerate()
+                    .map(|(x, b)| parse_fn(x, y, b))
+                    .collect::<Result<BitVec<S, O>>>()?,
+            );
+        }
+        Self::try_new(rows.into_iter().map(|row| row.into_iter().map(Ok)))
+    }
+
+    /// Cells are true for bytes in `true_chars`, false for anything else.
+    #[allow(dead_code)]
+    pub fn parse_true_chars(s: &str, true_chars: &[u8]) -> Result<Self> {
+        Self::parse_with(s, |_, _, b| Ok(true_chars.contains(&b)))
+    }
+
+    /// Copy of any bool grid, e.g. to materialize a
+    /// [`crate::utils::grid::transform`] view.
+    #[allow(dead_code)]
+    pub fn from_grid<G: Grid2d<bool>>(grid: &G) -> Self {
+        let grid_bits = (0..grid.height())
+            .flat_map(|y| (0..grid.width()).map(move |x| grid[(x, y)]))
+            .collect::<BitVec<S, O>>();
+        Self {
+            grid: grid_bits,
+            grid_x_significant: OnceCell::default(),
+            height: grid.height(),
+            width: grid.width(),
+        }
+    }
+
     pub fn rows(&self) -> Chunks<'_, S, O> {
         return self.grid.chunks(self.width);
     }
@@ -175,7 +272,6 @@ impl<S: BitStore, O: BitOrder> Grid2dBitVec<S, O> {
         y * self.width + x
     }
 
-    #[allow(dead_code)]
     fn flatten_idx_x_significant(&self, x: usize, y: usize) -> usize {
         assert!(y < self.height);
         assert!(x < self.width);
@@ -187,3 +283,49 @@ impl<S: BitStore, O: BitOrder> Grid2dBitVec<S, O> {
         &self.grid
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::utils::grid::grid_2d_bitvec::Grid2dBitVec;
+    use crate::utils::grid::{Grid2d, Grid2dMut};
+
+    /// Also builds the column cache, so the next write has to keep it in sync.
+    fn assert_cols_match_rows(grid: &Grid2dBitVec) {
+        assert_eq!(grid.cols().count(), grid.width());
+        for x in 0..grid.width() {
+            let expected = (0..grid.height()).map(|y| grid[(x, y)]).collect::<Vec<_>>();
+            assert_eq!(grid.get_col(x).iter().by_vals().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn test_mutation_keeps_column_cache() -> Result<()> {
+        let mut grid = Grid2dBitVec::parse_true_chars("#..#\n.##.\n#...", b"#")?;
+        assert_cols_match_rows(&grid);
+
+        assert!(!grid.set(1, 2, true));
+        assert_cols_match_rows(&grid);
+        grid.swap((0, 0), (3, 2));
+        assert_eq!(grid.to_string(), "...#\n.##.\n##.#");
+        assert_cols_match_rows(&grid);
+
+        grid.set_row(1, [true, false, false, true]);
+        assert_cols_match_rows(&grid);
+        grid.set_col(2, [true, true, true]);
+        assert_eq!(grid.to_string(), "..##\n#.##\n####");
+        assert_cols_match_rows(&grid);
+
+        grid.map_in_place(|x, y, &b| if x == y { !b } else { b });
+        assert_eq!(grid.to_string(), "#.##\n####\n##.#");
+        assert_cols_match_rows(&grid);
+        grid.map_col_in_place(3, |_, &b| !b);
+        assert_cols_match_rows(&grid);
+
+        grid.fill(false);
+        assert_eq!(grid.to_string(), "....\n....\n....");
+        assert_cols_match_rows(&grid);
+        Ok(())
+    }
+}
@@ -1,17 +1,18 @@
 use std::cell::OnceCell;
-use std::fmt::Debug;
-use std::ops::Index;
+use std::fmt::{Debug, Display, Formatter};
+use std::mem;
+use std::ops::{Index, IndexMut};
 use std::slice::Iter;
 
 use anyhow::Result;
 use thiserror::Error;
 
-use crate::utils::grid::Grid2d;
+use crate::utils::grid::{Grid2d, Grid2dMut};
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error("Widths are not the same")]
-    InvalidWidth,
+    #[error("Row {row} (count from 0) has width {width}, expected {expected}")]
+    InvalidWidth { row: usize, width: usize, expected: usize },
 }
 
 #[derive(Debug)]
@@ -29,6 +30,19 @@ impl<T> Index<(usize, usize)> for Grid2dVec<T> {
     }
 }
 
+impl<T: Display> Display for Grid2dVec<T> {
+    /// See [`Grid2d::display_cells`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_cells())
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid2dVec<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self.grid[y][x]
+    }
+}
+
 impl<T> Grid2d<T> for Grid2dVec<T> {
     fn height(&self) -> usize {
         self.height
@@ -39,6 +53,34 @@ impl<T> Grid2d<T> for Grid2dVec<T> {
     }
 }
 
+impl<T> Grid2dMut<T> for Grid2dVec<T> {
+    fn set(&mut self, x: usize, y: usize, value: T) -> T {
+        mem::replace(&mut self[(x, y)], value)
+    }
+
+    fn swap(&mut self, (x1, y1): (usize, usize), (x2, y2): (usize, usize)) {
+        if y1 == y2 {
+            self.grid[y1].swap(x1, x2);
+        } else {
+            let (low, high) = self.grid.split_at_mut(y1.max(y2));
+            let (low, high) = (&mut low[y1.min(y2)], &mut high[0]);
+            let (cell_1, cell_2) = if y1 < y2 { (x1, x2) } else { (x2, x1) };
+            mem::swap(&mut low[cell_1], &mut high[cell_2]);
+        }
+    }
+
+    fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.grid.iter_mut().for_each(|row| row.fill(value.clone()));
+    }
+
+    fn map_row_in_place<F: FnMut(usize, &T) -> T>(&mut self, y: usize, mut map_fn: F) {
+        self.grid[y].iter_mut().enumerate().for_each(|(x, t)| *t = map_fn(x, t));
+    }
+}
+
 impl<T> Grid2dVec<T> {
     pub fn try_new<I: IntoIterator<Item = Result<T>>, II: IntoIterator<Item = I>>(
         into_iter: II,
@@ -49,12 +91,14 @@ impl<T> Grid2dVec<T> {
             .into_iter()
             .map(|i| i.into_iter())
             .map(Iterator::collect::<Result<Vec<_>>>)
-            .map(|line_res| {
+            .enumerate()
+            .map(|(row, line_res)| {
                 let line = line_res?;
-                if line.len() == *predict_width.get_or_init(|| line.len()) {
+                let &expected = predict_width.get_or_init(|| line.len());
+                if line.len() == expected {
                     Ok(line)
                 } else {
-                    Err(Error::InvalidWidth)?
+                    Err(Error::InvalidWidth { row, width: line.len(), expected })?
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -64,6 +108,46 @@ impl<T> Grid2dVec<T> {
         Ok(Self { grid, height, width })
     }
 
+    /// One cell per byte of each line, `parse_fn` gets the cell coordinate so
+    /// it can record special cells such as a start.
+    pub fn parse_with<F: FnMut(usize, usize, u8) -> Result<T>>(
+        s: &str,
+        mut parse_fn: F,
+    ) -> Result<Self> {
+        let mut rows = Vec::new();
+        for (y, line) in s.lines().enumerate() {
+            rows.push(
+                line.bytes()
+                    .enumerate()
+                    .map(|(x, b)| parse_fn(x, y, b))
+                    .collect::<Result<Vec<_>>>()?,
+            );
+        }
+        Self::try_new(rows.into_iter().map(|row| row.into_iter().map(Ok)))
+    }
+
+    /// Copy of any grid, e.g. to materialize a
+    /// [`crate::utils::grid::transform`] view.
+    #[allow(dead_code)]
+    pub fn from_grid<G: Grid2d<T>>(grid: &G) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(grid.width(), grid.height(), |x, y| grid[(x, y)].clone())
+    }
+
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(
+        width: usize,
+        height: usize,
+        mut cell_fn: F,
+    ) -> Self {
+        Grid2dVec {
+            grid: (0..height).map(|y| (0..width).map(|x| cell_fn(x, y)).collect()).collect(),
+            height,
+            width,
+        }
+    }
+
     pub fn rows(&self) -> Iter<'_, Vec<T>> {
         return self.grid.iter();
     }
@@ -0,0 +1,353 @@
+use std::collections::{HashSet, VecDeque};
+use std::marker::PhantomData;
+use std::ops::{ControlFlow, Index};
+
+use itertools::{iproduct, Itertools};
+
+use crate::utils::graph::bfs_full;
+use crate::utils::grid::point::{Point3, Vec3};
+use crate::utils::grid::Grid2d;
+use crate::utils::int_trait::Integer;
+
+/// Offsets to the 6 neighbors sharing a face.
+pub const FACE_OFFSETS: [Vec3<isize>; 6] = [
+    Vec3 { x: -1, y: 0, z: 0 },
+    Vec3 { x: 1, y: 0, z: 0 },
+    Vec3 { x: 0, y: -1, z: 0 },
+    Vec3 { x: 0, y: 1, z: 0 },
+    Vec3 { x: 0, y: 0, z: -1 },
+    Vec3 { x: 0, y: 0, z: 1 },
+];
+
+/// Offsets to the 26 neighbors sharing a face, an edge or a corner, layer by
+/// layer then row by row.
+pub const ALL_OFFSETS: [Vec3<isize>; 26] = all_offsets();
+
+const fn all_offsets() -> [Vec3<isize>; 26] {
+    let mut offsets = [Vec3 { x: 0, y: 0, z: 0 }; 26];
+    let mut i = 0;
+    let mut n = 0;
+    while n < 27 {
+        // 13 is the center of the 3x3x3 cube.
+        if n != 13 {
+            offsets[i] = Vec3 { x: n % 3 - 1, y: n / 3 % 3 - 1, z: n / 9 - 1 };
+            i += 1;
+        }
+        n += 1;
+    }
+    offsets
+}
+
+#[allow(dead_code)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Axis3 {
+    X,
+    Y,
+    Z,
+}
+
+/// Dense 3D grid indexed by `(x, y, z)`.
+#[allow(dead_code)]
+pub trait Grid3d<T>: Index<(usize, usize, usize), Output = T> {
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    fn depth(&self) -> usize;
+
+    fn size(&self) -> usize {
+        self.width() * self.height() * self.depth()
+    }
+
+    fn len_on(&self, axis: Axis3) -> usize {
+        match axis {
+            Axis3::X => self.width(),
+            Axis3::Y => self.height(),
+            Axis3::Z => self.depth(),
+        }
+    }
+
+    fn contains(&self, x: usize, y: usize, z: usize) -> bool {
+        x < self.width() && y < self.height() && z < self.depth()
+    }
+
+    fn get(&self, x: usize, y: usize, z: usize) -> Option<&T> {
+        self.contains(x, y, z).then(|| &self[(x, y, z)])
+    }
+
+    /// Cell at `point`, `None` outside the grid including negative
+    /// coordinates.
+    fn get_point<I: Integer>(&self, point: Point3<I>) -> Option<&T> {
+        point.try_cast::<usize>().and_then(|point| self.get(point.x, point.y, point.z))
+    }
+
+    /// Coordinate of `(x, y, z)` moved by `offset`, `None` outside the grid.
+    fn offset_coordinate(
+        &self,
+        x: usize,
+        y: usize,
+        z: usize,
+        offset: Vec3<isize>,
+    ) -> Option<(usize, usize, usize)> {
+        let x = x.checked_add_signed(offset.x)?;
+        let y = y.checked_add_signed(offset.y)?;
+        let z = z.checked_add_signed(offset.z)?;
+        self.contains(x, y, z).then_some((x, y, z))
+    }
+
+    fn neighbors_in<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        z: usize,
+        offsets: &'a [Vec3<isize>],
+    ) -> impl Iterator<Item = (usize, usize, usize, &'a T)> + 'a
+    where
+        Self: Sized,
+        T: 'a,
+    {
+        offsets.iter().filter_map(move |offset| {
+            let (x, y, z) = self.offset_coordinate(x, y, z, *offset)?;
+            Some((x, y, z, &self[(x, y, z)]))
+        })
+    }
+
+    /// Up to 6 neighbors sharing a face.
+    fn face_neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        z: usize,
+    ) -> impl Iterator<Item = (usize, usize, usize, &'a T)> + 'a
+    where
+        Self: Sized,
+        T: 'a,
+    {
+        self.neighbors_in(x, y, z, &FACE_OFFSETS)
+    }
+
+    /// Up to 26 neighbors sharing a face, an edge or a corner.
+    fn all_neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        z: usize,
+    ) -> impl Iterator<Item = (usize, usize, usize, &'a T)> + 'a
+    where
+        Self: Sized,
+        T: 'a,
+    {
+        self.neighbors_in(x, y, z, &ALL_OFFSETS)
+    }
+
+    /// 2D slice at `index` on `axis`, `None` if `index` is outside the grid.
+    fn layer(&self, axis: Axis3, index: usize) -> Option<Grid3dLayer<'_, T, Self>>
+    where
+        Self: Sized,
+    {
+        (index < self.len_on(axis)).then_some(Grid3dLayer {
+            grid: self,
+            axis,
+            index,
+            _cell: PhantomData,
+        })
+    }
+
+    fn layers(&self, axis: Axis3) -> impl Iterator<Item = Grid3dLayer<'_, T, Self>>
+    where
+        Self: Sized,
+    {
+        (0..self.len_on(axis)).map(move |index| Grid3dLayer {
+            grid: self,
+            axis,
+            index,
+            _cell: PhantomData,
+        })
+    }
+
+    /// Cells reachable from `start` through face neighbors matching
+    /// `passable`, `start` included whatever its value.
+    fn flood_fill<F: Fn(&T) -> bool>(
+        &self,
+        start: (usize, usize, usize),
+        passable: F,
+    ) -> HashSet<(usize, usize, usize)>
+    where
+        Self: Sized,
+    {
+        let neighbor_fn = |&(x, y, z): &(usize, usize, usize)| {
+            self.face_neighbors(x, y, z)
+                .filter(|(_, _, _, t)| passable(t))
+                .map(|(x, y, z, _)| (x, y, z))
+                .collect_vec()
+        };
+        match bfs_full(
+            VecDeque::from([((), start)]),
+            HashSet::default(),
+            neighbor_fn,
+            |_, _| false,
+            |_, _| (),
+        ) {
+            ControlFlow::Continue(visited) => visited,
+            ControlFlow::Break(_) => unreachable!("end state is never reached"),
+        }
+    }
+
+    /// Faces of solid cells not shared with another solid cell, counting
+    /// those of trapped air pockets.
+    fn surface_area<F: Fn(&T) -> bool>(&self, is_solid: F) -> usize
+    where
+        Self: Sized,
+    {
+        iproduct!(0..self.depth(), 0..self.height(), 0..self.width())
+            .filter(|&(z, y, x)| is_solid(&self[(x, y, z)]))
+            .map(|(z, y, x)| {
+                FACE_OFFSETS
+                    .iter()
+                    .filter(|offset| {
+                        self.offset_coordinate(x, y, z, **offset)
+                            .is_none_or(|coordinate| !is_solid(&self[coordinate]))
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Faces of solid cells reachable from outside the grid, flood filling the
+    /// non solid cells from a one cell border around it.
+    fn exterior_surface_area<F: Fn(&T) -> bool>(&self, is_solid: F) -> usize
+    where
+        Self: Sized,
+    {
+        let solid_at = |point: Point3<isize>| self.get_point(point).is_some_and(&is_solid);
+        let in_border = |point: &Point3<isize>| {
+            (-1..=self.width() as isize).contains(&point.x)
+                && (-1..=self.height() as isize).contains(&point.y)
+                && (-1..=self.depth() as isize).contains(&point.z)
+        };
+        let neighbor_fn = |point: &Point3<isize>| {
+            FACE_OFFSETS
+                .iter()
+                .map(|offset| *point + *offset)
+                .filter(|next| in_border(next) && !solid_at(*next))
+                .collect_vec()
+        };
+        let outside = match bfs_full(
+            VecDeque::from([((), Point3::new(-1, -1, -1))]),
+            HashSet::default(),
+            neighbor_fn,
+            |_, _| false,
+            |_, _| (),
+        ) {
+            ControlFlow::Continue(visited) => visited,
+            ControlFlow::Break(_) => unreachable!("end state is never reached"),
+        };
+        outside
+            .into_iter()
+            .map(|point| FACE_OFFSETS.iter().filter(|offset| solid_at(point + **offset)).count())
+            .sum()
+    }
+
+    /// Faces of solid cells facing air pockets unreachable from outside.
+    fn interior_surface_area<F: Fn(&T) -> bool>(&self, is_solid: F) -> usize
+    where
+        Self: Sized,
+    {
+        self.surface_area(&is_solid) - self.exterior_surface_area(&is_solid)
+    }
+}
+
+/// See [`Grid3d::layer`]. The 2 remaining axes become `(x, y)` in order, e.g.
+/// a layer on [`Axis3::Y`] is indexed by `(x, z)`.
+pub struct Grid3dLayer<'a, T, G: Grid3d<T>> {
+    grid: &'a G,
+    axis: Axis3,
+    index: usize,
+    _cell: PhantomData<T>,
+}
+
+impl<T, G: Grid3d<T>> Grid3dLayer<'_, T, G> {
+    fn source_coordinate(&self, x: usize, y: usize) -> (usize, usize, usize) {
+        match self.axis {
+            Axis3::X => (self.index, x, y),
+            Axis3::Y => (x, self.index, y),
+            Axis3::Z => (x, y, self.index),
+        }
+    }
+}
+
+impl<T, G: Grid3d<T>> Index<(usize, usize)> for Grid3dLayer<'_, T, G> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.grid[self.source_coordinate(x, y)]
+    }
+}
+
+impl<T, G: Grid3d<T>> Grid2d<T> for Grid3dLayer<'_, T, G> {
+    fn height(&self) -> usize {
+        match self.axis {
+            Axis3::X | Axis3::Y => self.grid.depth(),
+            Axis3::Z => self.grid.height(),
+        }
+    }
+
+    fn width(&self) -> usize {
+        match self.axis {
+            Axis3::X => self.grid.height(),
+            Axis3::Y | Axis3::Z => self.grid.width(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::grid::grid_3d::{Axis3, Grid3d};
+    use crate::utils::grid::grid_3d_vec::Grid3dVec;
+    use crate::utils::grid::Grid2d;
+
+    #[test]
+    fn test_single_voxel() {
+        let grid = Grid3dVec::new(1, 1, 1, true);
+        assert_eq!(grid.surface_area(|&b| b), 6);
+        assert_eq!(grid.exterior_surface_area(|&b| b), 6);
+        assert_eq!(grid.interior_surface_area(|&b| b), 0);
+
+        let grid = Grid3dVec::from_fn(3, 3, 3, |x, y, z| (x, y, z) == (1, 1, 1));
+        assert_eq!(grid.surface_area(|&b| b), 6);
+        assert_eq!(grid.exterior_surface_area(|&b| b), 6);
+        assert_eq!(grid.interior_surface_area(|&b| b), 0);
+    }
+
+    #[test]
+    fn test_hollow_shell() {
+        let grid = Grid3dVec::from_fn(3, 3, 3, |x, y, z| (x, y, z) != (1, 1, 1));
+        assert_eq!(grid.surface_area(|&b| b), 60);
+        assert_eq!(grid.exterior_surface_area(|&b| b), 54);
+        assert_eq!(grid.interior_surface_area(|&b| b), 6);
+        assert_eq!(grid.flood_fill((1, 1, 1), |&b| !b).len(), 1);
+        assert_eq!(grid.flood_fill((0, 0, 0), |&b| b).len(), 26);
+    }
+
+    #[test]
+    fn test_layers() {
+        let grid = Grid3dVec::from_fn(2, 3, 4, |x, y, z| (x, y, z));
+
+        let layer = grid.layer(Axis3::X, 1).unwrap();
+        assert_eq!((layer.width(), layer.height()), (3, 4));
+        assert_eq!(layer[(2, 3)], (1, 2, 3));
+
+        let layer = grid.layer(Axis3::Y, 2).unwrap();
+        assert_eq!((layer.width(), layer.height()), (2, 4));
+        assert_eq!(layer[(1, 3)], (1, 2, 3));
+
+        let layer = grid.layer(Axis3::Z, 3).unwrap();
+        assert_eq!((layer.width(), layer.height()), (2, 3));
+        assert_eq!(layer[(1, 2)], (1, 2, 3));
+
+        assert!(grid.layer(Axis3::X, 2).is_none());
+        assert!(grid.layer(Axis3::Z, 4).is_none());
+        assert_eq!(grid.layers(Axis3::Y).count(), 3);
+        assert!(grid.layers(Axis3::Z).enumerate().all(|(z, layer)| layer[(0, 0)] == (0, 0, z)));
+    }
+}
@@ -0,0 +1,84 @@
+use std::mem;
+use std::ops::{Index, IndexMut};
+
+use itertools::iproduct;
+
+use crate::utils::grid::grid_3d::Grid3d;
+
+/// Row major cells, layer by layer along z.
+#[derive(Clone, Debug)]
+pub struct Grid3dVec<T> {
+    grid: Vec<T>,
+    width: usize,
+    height: usize,
+    depth: usize,
+}
+
+#[allow(dead_code)]
+impl<T> Grid3dVec<T> {
+    pub fn new(width: usize, height: usize, depth: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid3dVec { grid: vec![value; width * height * depth], width, height, depth }
+    }
+
+    pub fn from_fn<F: FnMut(usize, usize, usize) -> T>(
+        width: usize,
+        height: usize,
+        depth: usize,
+        mut cell_fn: F,
+    ) -> Self {
+        let grid = iproduct!(0..depth, 0..height, 0..width)
+            .map(|(z, y, x)| cell_fn(x, y, z))
+            .collect::<Vec<_>>();
+        Grid3dVec { grid, width, height, depth }
+    }
+
+    pub fn from_grid<G: Grid3d<T>>(grid: &G) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(grid.width(), grid.height(), grid.depth(), |x, y, z| grid[(x, y, z)].clone())
+    }
+
+    pub fn flatten_idx(&self, x: usize, y: usize, z: usize) -> usize {
+        (z * self.height + y) * self.width + x
+    }
+
+    /// Set cell at `(x, y, z)`, returns the previous value.
+    pub fn set(&mut self, x: usize, y: usize, z: usize, value: T) -> T {
+        mem::replace(&mut self[(x, y, z)], value)
+    }
+}
+
+impl<T> Index<(usize, usize, usize)> for Grid3dVec<T> {
+    type Output = T;
+
+    fn index(&self, (x, y, z): (usize, usize, usize)) -> &Self::Output {
+        assert!(self.contains(x, y, z));
+        &self.grid[self.flatten_idx(x, y, z)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize, usize)> for Grid3dVec<T> {
+    fn index_mut(&mut self, (x, y, z): (usize, usize, usize)) -> &mut Self::Output {
+        assert!(self.contains(x, y, z));
+        let idx = self.flatten_idx(x, y, z);
+        &mut self.grid[idx]
+    }
+}
+
+impl<T> Grid3d<T> for Grid3dVec<T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn depth(&self) -> usize {
+        self.depth
+    }
+}
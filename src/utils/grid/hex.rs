@@ -0,0 +1,446 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use derive_new::new;
+use enumset::EnumSetType;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Invalid hex direction {0:?}")]
+    InvalidDirection(String),
+}
+
+/// Directions of a pointy topped hex grid, rows are horizontal and there is
+/// no north or south neighbor.
+#[derive(EnumSetType, Hash, Debug)]
+pub enum HexDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+/// Directions of a flat topped hex grid, columns are vertical and there is no
+/// east or west neighbor.
+#[derive(EnumSetType, Hash, Debug)]
+pub enum FlatHexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+/// Axial coordinate. On a pointy topped grid `q` grows to the east and `r` to
+/// the south east, on a flat topped grid `q` grows to the south east and `r` to
+/// the south.
+#[derive(new, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Hex {
+    pub q: isize,
+    pub r: isize,
+}
+
+/// Cube coordinate of a [`Hex`], `q + r + s` is always 0.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Cube {
+    pub q: isize,
+    pub r: isize,
+    pub s: isize,
+}
+
+#[allow(dead_code)]
+impl HexDirection {
+    /// Counter clockwise starting from east, the order [`Hex::ring`] walks.
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::NorthEast,
+        HexDirection::NorthWest,
+        HexDirection::West,
+        HexDirection::SouthWest,
+        HexDirection::SouthEast,
+    ];
+
+    pub const fn reverse(&self) -> HexDirection {
+        match self {
+            HexDirection::East => HexDirection::West,
+            HexDirection::NorthEast => HexDirection::SouthWest,
+            HexDirection::NorthWest => HexDirection::SouthEast,
+            HexDirection::West => HexDirection::East,
+            HexDirection::SouthWest => HexDirection::NorthEast,
+            HexDirection::SouthEast => HexDirection::NorthWest,
+        }
+    }
+
+    pub fn clock_wise_60(&self) -> HexDirection {
+        match self {
+            HexDirection::East => HexDirection::SouthEast,
+            HexDirection::NorthEast => HexDirection::East,
+            HexDirection::NorthWest => HexDirection::NorthEast,
+            HexDirection::West => HexDirection::NorthWest,
+            HexDirection::SouthWest => HexDirection::West,
+            HexDirection::SouthEast => HexDirection::SouthWest,
+        }
+    }
+
+    /// Parse directions written back to back without separator, e.g.
+    /// `"esenee"`.
+    pub fn parse_run(s: &str) -> Result<Vec<HexDirection>, Error> {
+        let mut directions = Vec::new();
+        let mut rest = s;
+        while let Some(first) = rest.chars().next() {
+            let (direction, next) = HexDirection::ALL
+                .iter()
+                .find_map(|direction| {
+                    rest.strip_prefix(&direction.to_string()).map(|next| (*direction, next))
+                })
+                .ok_or_else(|| Error::InvalidDirection(first.to_string()))?;
+            directions.push(direction);
+            rest = next;
+        }
+        Ok(directions)
+    }
+}
+
+impl FromStr for HexDirection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e" => Ok(HexDirection::East),
+            "ne" => Ok(HexDirection::NorthEast),
+            "nw" => Ok(HexDirection::NorthWest),
+            "w" => Ok(HexDirection::West),
+            "sw" => Ok(HexDirection::SouthWest),
+            "se" => Ok(HexDirection::SouthEast),
+            _ => Err(Error::InvalidDirection(s.to_owned())),
+        }
+    }
+}
+
+/// Short form as written in puzzle inputs, e.g. `"ne"`.
+impl Display for HexDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            HexDirection::East => "e",
+            HexDirection::NorthEast => "ne",
+            HexDirection::NorthWest => "nw",
+            HexDirection::West => "w",
+            HexDirection::SouthWest => "sw",
+            HexDirection::SouthEast => "se",
+        })
+    }
+}
+
+/// Unit offset.
+impl From<HexDirection> for Hex {
+    fn from(direction: HexDirection) -> Self {
+        let (q, r) = match direction {
+            HexDirection::East => (1, 0),
+            HexDirection::NorthEast => (1, -1),
+            HexDirection::NorthWest => (0, -1),
+            HexDirection::West => (-1, 0),
+            HexDirection::SouthWest => (-1, 1),
+            HexDirection::SouthEast => (0, 1),
+        };
+        Hex { q, r }
+    }
+}
+
+#[allow(dead_code)]
+impl FlatHexDirection {
+    /// Clockwise starting from north.
+    pub const ALL: [FlatHexDirection; 6] = [
+        FlatHexDirection::North,
+        FlatHexDirection::NorthEast,
+        FlatHexDirection::SouthEast,
+        FlatHexDirection::South,
+        FlatHexDirection::SouthWest,
+        FlatHexDirection::NorthWest,
+    ];
+
+    pub const fn reverse(&self) -> FlatHexDirection {
+        match self {
+            FlatHexDirection::North => FlatHexDirection::South,
+            FlatHexDirection::NorthEast => FlatHexDirection::SouthWest,
+            FlatHexDirection::SouthEast => FlatHexDirection::NorthWest,
+            FlatHexDirection::South => FlatHexDirection::North,
+            FlatHexDirection::SouthWest => FlatHexDirection::NorthEast,
+            FlatHexDirection::NorthWest => FlatHexDirection::SouthEast,
+        }
+    }
+
+    pub fn clock_wise_60(&self) -> FlatHexDirection {
+        match self {
+            FlatHexDirection::North => FlatHexDirection::NorthEast,
+            FlatHexDirection::NorthEast => FlatHexDirection::SouthEast,
+            FlatHexDirection::SouthEast => FlatHexDirection::South,
+            FlatHexDirection::South => FlatHexDirection::SouthWest,
+            FlatHexDirection::SouthWest => FlatHexDirection::NorthWest,
+            FlatHexDirection::NorthWest => FlatHexDirection::North,
+        }
+    }
+}
+
+impl FromStr for FlatHexDirection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "n" => Ok(FlatHexDirection::North),
+            "ne" => Ok(FlatHexDirection::NorthEast),
+            "se" => Ok(FlatHexDirection::SouthEast),
+            "s" => Ok(FlatHexDirection::South),
+            "sw" => Ok(FlatHexDirection::SouthWest),
+            "nw" => Ok(FlatHexDirection::NorthWest),
+            _ => Err(Error::InvalidDirection(s.to_owned())),
+        }
+    }
+}
+
+/// Short form as written in puzzle inputs, e.g. `"ne"`.
+impl Display for FlatHexDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            FlatHexDirection::North => "n",
+            FlatHexDirection::NorthEast => "ne",
+            FlatHexDirection::SouthEast => "se",
+            FlatHexDirection::South => "s",
+            FlatHexDirection::SouthWest => "sw",
+            FlatHexDirection::NorthWest => "nw",
+        })
+    }
+}
+
+/// Unit offset.
+impl From<FlatHexDirection> for Hex {
+    fn from(direction: FlatHexDirection) -> Self {
+        let (q, r) = match direction {
+            FlatHexDirection::North => (0, -1),
+            FlatHexDirection::NorthEast => (1, -1),
+            FlatHexDirection::SouthEast => (1, 0),
+            FlatHexDirection::South => (0, 1),
+            FlatHexDirection::SouthWest => (-1, 1),
+            FlatHexDirection::NorthWest => (-1, 0),
+        };
+        Hex { q, r }
+    }
+}
+
+#[allow(dead_code)]
+impl Hex {
+    pub fn s(&self) -> isize {
+        -self.q - self.r
+    }
+
+    pub fn to_cube(self) -> Cube {
+        Cube::from(self)
+    }
+
+    /// Works with both [`HexDirection`] and [`FlatHexDirection`].
+    pub fn neighbor<D: Into<Hex>>(&self, direction: D) -> Hex {
+        *self + direction.into()
+    }
+
+    /// Number of steps to `other`.
+    pub fn distance(&self, other: &Hex) -> usize {
+        (*self - *other).distance_from_origin()
+    }
+
+    pub fn distance_from_origin(&self) -> usize {
+        self.q.unsigned_abs().max(self.r.unsigned_abs()).max(self.s().unsigned_abs())
+    }
+
+    /// Cells at exactly `radius` steps, walking counter clockwise from the
+    /// south west corner. Radius 0 is the cell itself.
+    pub fn ring(&self, radius: usize) -> impl Iterator<Item = Hex> {
+        let center = *self;
+        let count = if radius == 0 { 1 } else { 6 * radius };
+        (0..count).map(move |k| {
+            if radius == 0 {
+                return center;
+            }
+            let (side, step) = (k / radius, k % radius);
+            let corner = Hex::from(HexDirection::ALL[(side + 4) % 6]) * radius as isize;
+            center + corner + Hex::from(HexDirection::ALL[side]) * step as isize
+        })
+    }
+
+    /// Cells within `radius` steps, ring by ring from the center.
+    pub fn spiral(&self, radius: usize) -> impl Iterator<Item = Hex> {
+        let center = *self;
+        (0..=radius).flat_map(move |radius| center.ring(radius))
+    }
+}
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
+
+impl From<Hex> for Cube {
+    fn from(hex: Hex) -> Self {
+        Cube { q: hex.q, r: hex.r, s: hex.s() }
+    }
+}
+
+impl From<Cube> for Hex {
+    fn from(cube: Cube) -> Self {
+        Hex { q: cube.q, r: cube.r }
+    }
+}
+
+#[allow(dead_code)]
+impl Cube {
+    /// `None` unless `q + r + s == 0`.
+    pub fn new(q: isize, r: isize, s: isize) -> Option<Cube> {
+        (q + r + s == 0).then_some(Cube { q, r, s })
+    }
+
+    pub fn distance(&self, other: &Cube) -> usize {
+        self.q.abs_diff(other.q).max(self.r.abs_diff(other.r)).max(self.s.abs_diff(other.s))
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: Hex) -> Self::Output {
+        Hex { q: self.q + rhs.q, r: self.r + rhs.r }
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, rhs: Hex) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, rhs: Hex) -> Self::Output {
+        Hex { q: self.q - rhs.q, r: self.r - rhs.r }
+    }
+}
+
+impl SubAssign for Hex {
+    fn sub_assign(&mut self, rhs: Hex) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<isize> for Hex {
+    type Output = Hex;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Hex { q: self.q * rhs, r: self.r * rhs }
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+
+    fn neg(self) -> Self::Output {
+        Hex { q: -self.q, r: -self.r }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::utils::grid::hex::{Error, FlatHexDirection, Hex, HexDirection};
+
+    #[test]
+    fn test_distance() {
+        let origin = Hex::default();
+        assert_eq!(origin.distance(&origin), 0);
+        assert_eq!(Hex::new(3, -1).distance(&origin), 3);
+        assert_eq!(Hex::new(-1, 1).distance(&Hex::new(2, -3)), 4);
+        assert_eq!(Hex::new(2, -3).distance(&Hex::new(-1, 1)), 4);
+        assert_eq!(Hex::new(-2, -2).distance_from_origin(), 4);
+        assert_eq!(Hex::new(-1, 1).to_cube().distance(&Hex::new(2, -3).to_cube()), 4);
+        for direction in HexDirection::ALL {
+            assert_eq!(origin.neighbor(direction).distance(&origin), 1);
+        }
+    }
+
+    #[test]
+    fn test_ring() {
+        let center = Hex::new(2, -1);
+        assert_eq!(center.ring(0).collect_vec(), [center]);
+        assert_eq!(
+            Hex::default().ring(1).collect_vec(),
+            [
+                HexDirection::SouthWest,
+                HexDirection::SouthEast,
+                HexDirection::East,
+                HexDirection::NorthEast,
+                HexDirection::NorthWest,
+                HexDirection::West,
+            ]
+            .map(Hex::from)
+        );
+        for radius in 1..=4 {
+            let ring = center.ring(radius).collect_vec();
+            assert_eq!(ring.len(), 6 * radius);
+            assert_eq!(ring.iter().unique().count(), ring.len());
+            assert_eq!(ring[0], center + Hex::from(HexDirection::SouthWest) * radius as isize);
+            assert!(ring.iter().all(|hex| hex.distance(&center) == radius));
+            assert!(ring.iter().circular_tuple_windows().all(|(a, b)| a.distance(b) == 1));
+            assert_eq!(ring[1], ring[0].neighbor(HexDirection::East));
+        }
+    }
+
+    #[test]
+    fn test_spiral() {
+        let center = Hex::new(-3, 5);
+        for radius in 0..=4 {
+            let spiral = center.spiral(radius).collect_vec();
+            assert_eq!(spiral.len(), 1 + 3 * radius * (radius + 1));
+            assert_eq!(spiral.iter().unique().count(), spiral.len());
+            assert_eq!(spiral[0], center);
+            assert!(spiral.iter().map(|hex| hex.distance(&center)).is_sorted());
+            assert!(spiral.iter().all(|hex| hex.distance(&center) <= radius));
+        }
+    }
+
+    #[test]
+    fn test_reverse_and_rotate() {
+        for (i, direction) in HexDirection::ALL.into_iter().enumerate() {
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(Hex::from(direction) + Hex::from(direction.reverse()), Hex::default());
+            assert_eq!(direction.clock_wise_60(), HexDirection::ALL[(i + 5) % 6]);
+            assert_eq!((0..3).fold(direction, |d, _| d.clock_wise_60()), direction.reverse());
+        }
+        for (i, direction) in FlatHexDirection::ALL.into_iter().enumerate() {
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(Hex::from(direction) + Hex::from(direction.reverse()), Hex::default());
+            assert_eq!(direction.clock_wise_60(), FlatHexDirection::ALL[(i + 1) % 6]);
+            assert_eq!((0..3).fold(direction, |d, _| d.clock_wise_60()), direction.reverse());
+        }
+    }
+
+    #[test]
+    fn test_parse_run() {
+        use HexDirection::*;
+        assert_eq!(HexDirection::parse_run("esenee").unwrap(), [East, SouthEast, NorthEast, East]);
+        assert_eq!(
+            HexDirection::parse_run("nwwswee").unwrap(),
+            [NorthWest, West, SouthWest, East, East]
+        );
+        assert!(HexDirection::parse_run("").unwrap().is_empty());
+        for invalid in ["é", "nö", "eé", "n", "ex", "N"] {
+            assert!(
+                matches!(HexDirection::parse_run(invalid), Err(Error::InvalidDirection(_))),
+                "{invalid:?} should not parse"
+            );
+        }
+    }
+}
@@ -1,10 +1,24 @@
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
 use std::ops::Index;
 
-use derive_more::Display;
 use enumset::EnumSetType;
 
+use crate::utils::grid::point::Point2;
+use crate::utils::int_trait::Integer;
+
 pub mod grid_2d_bitvec;
 pub mod grid_2d_vec;
+pub mod grid_3d;
+pub mod grid_3d_vec;
+pub mod hex;
+pub mod neighbor;
+pub mod point;
+pub mod region;
+pub mod sparse;
+pub mod sparse_3d;
+pub mod tiled;
+pub mod transform;
 
 pub trait Grid2d<T>: Index<(usize, usize), Output = T> {
     fn height(&self) -> usize;
@@ -26,6 +40,18 @@ pub trait Grid2d<T>: Index<(usize, usize), Output = T> {
         x < self.width() && y < self.height()
     }
 
+    /// Cell at `point`, `None` outside the grid including negative
+    /// coordinates.
+    #[allow(dead_code)]
+    fn get_point<I: Integer>(&self, point: Point2<I>) -> Option<&T> {
+        point.try_cast::<usize>().and_then(|point| self.get(point.x, point.y))
+    }
+
+    #[allow(dead_code)]
+    fn contains_point<I: Integer>(&self, point: Point2<I>) -> bool {
+        point.try_cast::<usize>().is_some_and(|point| self.contains(point.x, point.y))
+    }
+
     fn north_coordinate_from(&self, x: usize, y: usize, step: usize) -> Option<(usize, usize)> {
         y.checked_sub(step).filter(|y| self.contains(x, *y)).map(|y| (x, y))
     }
@@ -124,9 +150,100 @@ pub trait Grid2d<T>: Index<(usize, usize), Output = T> {
         }
         .map(|(x, y)| (x, y, &self[(x, y)]))
     }
+
+    /// Render one char per cell, rows separated by a newline.
+    fn display_with<F: Fn(usize, usize, &T) -> char>(
+        &self,
+        cell_fn: F,
+    ) -> GridDisplay<'_, T, Self, F>
+    where
+        Self: Sized,
+    {
+        GridDisplay { grid: self, cell_fn, _cell: PhantomData }
+    }
+
+    /// [`Grid2d::display_with`] the first char of each cell, cells must
+    /// render to a single char each to keep columns aligned.
+    fn display_cells<'a>(&'a self) -> impl Display + 'a
+    where
+        Self: Sized,
+        T: Display + 'a,
+    {
+        self.display_with(|_, _, t| t.to_string().chars().next().unwrap_or(' '))
+    }
+}
+
+/// See [`Grid2d::display_with`].
+pub struct GridDisplay<'a, T, G: Grid2d<T>, F: Fn(usize, usize, &T) -> char> {
+    grid: &'a G,
+    cell_fn: F,
+    _cell: PhantomData<T>,
+}
+
+impl<T, G: Grid2d<T>, F: Fn(usize, usize, &T) -> char> Display for GridDisplay<'_, T, G, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.grid.height() {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+            let row = (0..self.grid.width())
+                .map(|x| (self.cell_fn)(x, y, &self.grid[(x, y)]))
+                .collect::<String>();
+            f.write_str(&row)?;
+        }
+        Ok(())
+    }
 }
 
-#[derive(EnumSetType, Hash, Display, Debug)]
+/// In place mutation of a [`Grid2d`], cells are written by value so bit
+/// backed grids can implement it too.
+#[allow(dead_code)]
+pub trait Grid2dMut<T>: Grid2d<T> {
+    /// Set cell at `(x, y)`, returns the previous value.
+    fn set(&mut self, x: usize, y: usize, value: T) -> T;
+
+    fn swap(&mut self, first: (usize, usize), second: (usize, usize));
+
+    fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        (0..self.height())
+            .for_each(|y| (0..self.width()).for_each(|x| drop(self.set(x, y, value.clone()))));
+    }
+
+    /// Replace row `y` from `values`, stops early if `values` is shorter than
+    /// the width.
+    fn set_row<I: IntoIterator<Item = T>>(&mut self, y: usize, values: I) {
+        (0..self.width()).zip(values).for_each(|(x, value)| drop(self.set(x, y, value)));
+    }
+
+    /// Replace column `x` from `values`, stops early if `values` is shorter
+    /// than the height.
+    fn set_col<I: IntoIterator<Item = T>>(&mut self, x: usize, values: I) {
+        (0..self.height()).zip(values).for_each(|(y, value)| drop(self.set(x, y, value)));
+    }
+
+    fn map_row_in_place<F: FnMut(usize, &T) -> T>(&mut self, y: usize, mut map_fn: F) {
+        (0..self.width()).for_each(|x| {
+            let value = map_fn(x, &self[(x, y)]);
+            self.set(x, y, value);
+        });
+    }
+
+    fn map_col_in_place<F: FnMut(usize, &T) -> T>(&mut self, x: usize, mut map_fn: F) {
+        (0..self.height()).for_each(|y| {
+            let value = map_fn(y, &self[(x, y)]);
+            self.set(x, y, value);
+        });
+    }
+
+    fn map_in_place<F: FnMut(usize, usize, &T) -> T>(&mut self, mut map_fn: F) {
+        (0..self.height()).for_each(|y| self.map_row_in_place(y, |x, t| map_fn(x, y, t)));
+    }
+}
+
+#[derive(EnumSetType, Hash, derive_more::Display, Debug)]
 pub enum GridDirection {
     North,
     South,
@@ -139,6 +256,20 @@ pub enum GridDirection {
 }
 
 impl GridDirection {
+    pub const CARDINAL: [GridDirection; 4] =
+        [GridDirection::North, GridDirection::South, GridDirection::East, GridDirection::West];
+
+    pub const ALL: [GridDirection; 8] = [
+        GridDirection::North,
+        GridDirection::South,
+        GridDirection::East,
+        GridDirection::West,
+        GridDirection::SouthWest,
+        GridDirection::SouthEast,
+        GridDirection::NorthEast,
+        GridDirection::NorthWest,
+    ];
+
     pub const fn reverse(&self) -> GridDirection {
         match self {
             GridDirection::North => GridDirection::South,
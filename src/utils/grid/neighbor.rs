@@ -0,0 +1,259 @@
+use crate::utils::grid::point::Vec2;
+use crate::utils::grid::{Grid2d, GridDirection};
+
+/// `(coordinate, direction, cell)` for each of `directions`, `step_fn` maps
+/// the unit offset of a direction to its coordinate and cell, `None` skips it.
+/// Shared by bounded, wrapped, sparse and tiled grids.
+pub fn direction_neighbors<'a, P, R, I, F>(
+    directions: I,
+    step_fn: F,
+) -> impl Iterator<Item = (P, GridDirection, R)> + 'a
+where
+    I: IntoIterator<Item = GridDirection> + 'a,
+    F: Fn(Vec2<isize>) -> Option<(P, R)> + 'a,
+{
+    directions.into_iter().filter_map(move |direction| {
+        let (coordinate, cell) = step_fn(Vec2::from(direction))?;
+        Some((coordinate, direction, cell))
+    })
+}
+
+/// Neighbor iterators of any [`Grid2d`]. Direction neighbors yield
+/// `(x, y, direction, cell)`, radius neighbors yield `(x, y, offset, cell)`.
+/// The `_wrapped` variants treat the grid as a torus, leaving one edge enters
+/// from the opposite one.
+#[allow(dead_code)]
+pub trait Grid2dNeighbor<T>: Grid2d<T> + Sized {
+    /// Coordinate of `(x, y)` moved by `offset`, `None` outside the grid.
+    fn offset_coordinate(&self, x: usize, y: usize, offset: Vec2<isize>) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(offset.x)?;
+        let y = y.checked_add_signed(offset.y)?;
+        self.contains(x, y).then_some((x, y))
+    }
+
+    /// Coordinate of `(x, y)` moved by `offset`, wrapping around the edges.
+    /// `None` only for an empty grid.
+    fn wrapped_offset_coordinate(
+        &self,
+        x: usize,
+        y: usize,
+        offset: Vec2<isize>,
+    ) -> Option<(usize, usize)> {
+        let wrap = |v: usize, offset: isize, len: usize| {
+            (len > 0).then(|| (v as isize + offset).rem_euclid(len as isize) as usize)
+        };
+        Some((wrap(x, offset.x, self.width())?, wrap(y, offset.y, self.height())?))
+    }
+
+    fn neighbors_in<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        directions: &'a [GridDirection],
+    ) -> impl Iterator<Item = (usize, usize, GridDirection, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        direction_neighbors(directions.iter().copied(), move |offset| {
+            let (x, y) = self.offset_coordinate(x, y, offset)?;
+            Some(((x, y), &self[(x, y)]))
+        })
+        .map(|((x, y), direction, t)| (x, y, direction, t))
+    }
+
+    fn wrapped_neighbors_in<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        directions: &'a [GridDirection],
+    ) -> impl Iterator<Item = (usize, usize, GridDirection, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        direction_neighbors(directions.iter().copied(), move |offset| {
+            let (x, y) = self.wrapped_offset_coordinate(x, y, offset)?;
+            Some(((x, y), &self[(x, y)]))
+        })
+        .map(|((x, y), direction, t)| (x, y, direction, t))
+    }
+
+    /// North, south, east and west neighbors inside the grid.
+    fn cardinal_neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize, GridDirection, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        self.neighbors_in(x, y, &GridDirection::CARDINAL)
+    }
+
+    /// Cardinal and diagonal neighbors inside the grid.
+    fn all_neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize, GridDirection, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        self.neighbors_in(x, y, &GridDirection::ALL)
+    }
+
+    fn wrapped_cardinal_neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize, GridDirection, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        self.wrapped_neighbors_in(x, y, &GridDirection::CARDINAL)
+    }
+
+    fn wrapped_all_neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize, GridDirection, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        self.wrapped_neighbors_in(x, y, &GridDirection::ALL)
+    }
+
+    /// Cardinal neighbors whose cell matches `predicate`, e.g. not a wall.
+    fn cardinal_neighbors_where<'a, F: Fn(&T) -> bool + 'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        predicate: F,
+    ) -> impl Iterator<Item = (usize, usize, GridDirection, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        self.cardinal_neighbors(x, y).filter(move |(_, _, _, t)| predicate(t))
+    }
+
+    /// Cells at manhattan distance `1..=radius` inside the grid, row by row.
+    fn manhattan_neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        radius: usize,
+    ) -> impl Iterator<Item = (usize, usize, Vec2<isize>, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        let radius = radius as isize;
+        (-radius..=radius)
+            .flat_map(move |dy| {
+                let width = radius - dy.abs();
+                (-width..=width).map(move |dx| Vec2::new(dx, dy))
+            })
+            .filter(|offset| *offset != Vec2::default())
+            .filter_map(move |offset| {
+                let (x, y) = self.offset_coordinate(x, y, offset)?;
+                Some((x, y, offset, &self[(x, y)]))
+            })
+    }
+
+    /// Cells at chebyshev distance `1..=radius` inside the grid, row by row.
+    fn chebyshev_neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        radius: usize,
+    ) -> impl Iterator<Item = (usize, usize, Vec2<isize>, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        let radius = radius as isize;
+        (-radius..=radius)
+            .flat_map(move |dy| (-radius..=radius).map(move |dx| Vec2::new(dx, dy)))
+            .filter(|offset| *offset != Vec2::default())
+            .filter_map(move |offset| {
+                let (x, y) = self.offset_coordinate(x, y, offset)?;
+                Some((x, y, offset, &self[(x, y)]))
+            })
+    }
+}
+
+impl<T, G: Grid2d<T>> Grid2dNeighbor<T> for G {}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use itertools::Itertools;
+
+    use crate::utils::grid::grid_2d_vec::Grid2dVec;
+    use crate::utils::grid::neighbor::Grid2dNeighbor;
+    use crate::utils::grid::point::Vec2;
+    use crate::utils::grid::GridDirection;
+
+    /// Letters in row major order.
+    fn sample() -> Result<Grid2dVec<char>> {
+        Grid2dVec::parse_with("abcd\nefgh\nijkl", |_, _, b| Ok(b as char))
+    }
+
+    fn cells<'a, D>(iter: impl Iterator<Item = (usize, usize, D, &'a char)>) -> String {
+        iter.map(|(_, _, _, c)| *c).sorted().collect()
+    }
+
+    #[test]
+    fn test_edge_filtering() -> Result<()> {
+        let grid = sample()?;
+        assert_eq!(cells(grid.cardinal_neighbors(1, 1)), "begj");
+        assert_eq!(cells(grid.all_neighbors(1, 1)), "abcegijk");
+        assert_eq!(cells(grid.cardinal_neighbors(0, 0)), "be");
+        assert_eq!(cells(grid.all_neighbors(0, 0)), "bef");
+        assert_eq!(cells(grid.all_neighbors(3, 2)), "ghk");
+        assert_eq!(cells(grid.cardinal_neighbors(2, 0)), "bdg");
+        assert_eq!(
+            grid.cardinal_neighbors(0, 0).map(|(x, y, d, _)| (x, y, d)).collect_vec(),
+            [(0, 1, GridDirection::South), (1, 0, GridDirection::East)]
+        );
+        assert_eq!(cells(grid.cardinal_neighbors_where(1, 1, |&c| c != 'b')), "egj");
+        assert_eq!(grid.offset_coordinate(3, 2, Vec2::new(1, 0)), None);
+        assert_eq!(grid.offset_coordinate(0, 1, Vec2::new(3, 1)), Some((3, 2)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrapped() -> Result<()> {
+        let grid = sample()?;
+        assert_eq!(cells(grid.wrapped_cardinal_neighbors(0, 0)), "bdei");
+        assert_eq!(cells(grid.wrapped_all_neighbors(0, 0)), "bdefhijl");
+        assert_eq!(cells(grid.wrapped_all_neighbors(1, 1)), cells(grid.all_neighbors(1, 1)));
+        assert_eq!(
+            grid.wrapped_neighbors_in(3, 2, &[GridDirection::East, GridDirection::SouthEast])
+                .map(|(x, y, _, c)| (x, y, *c))
+                .collect_vec(),
+            [(0, 2, 'i'), (0, 0, 'a')]
+        );
+        assert_eq!(grid.wrapped_offset_coordinate(1, 1, Vec2::new(-6, 7)), Some((3, 2)));
+        let empty = Grid2dVec::<char>::from_fn(0, 0, |_, _| '.');
+        assert_eq!(empty.wrapped_offset_coordinate(0, 0, Vec2::new(1, 0)), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_radius_counts() {
+        let grid = Grid2dVec::from_fn(11, 11, |x, y| (x, y));
+        for radius in 0..=5 {
+            let manhattan = grid.manhattan_neighbors(5, 5, radius).collect_vec();
+            assert_eq!(manhattan.len(), 2 * radius * (radius + 1));
+            assert!(manhattan.iter().all(|(x, y, offset, &cell)| {
+                cell == (*x, *y) && (1..=radius as isize).contains(&offset.manhattan_len())
+            }));
+            let chebyshev = grid.chebyshev_neighbors(5, 5, radius).collect_vec();
+            assert_eq!(chebyshev.len(), (2 * radius + 1).pow(2) - 1);
+            assert!(chebyshev.iter().all(|(x, y, offset, &cell)| {
+                cell == (*x, *y) && (1..=radius as isize).contains(&offset.chebyshev_len())
+            }));
+        }
+        assert_eq!(grid.manhattan_neighbors(0, 0, 2).count(), 5);
+        assert_eq!(grid.chebyshev_neighbors(0, 0, 2).count(), 8);
+    }
+}
@@ -0,0 +1,357 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use derive_new::new;
+use num::Signed;
+
+use crate::utils::grid::GridDirection;
+use crate::utils::int_trait::Integer;
+
+/// Grid position, y grows to the south like row indices.
+#[derive(new, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Point2<T: Integer> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Offset between two [`Point2`].
+#[derive(new, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Vec2<T: Integer> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Voxel position, x and y like [`Point2`], z grows upward like layer indices.
+#[derive(new, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Point3<T: Integer> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Offset between two [`Point3`].
+#[derive(new, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Vec3<T: Integer> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+#[allow(dead_code)]
+fn abs_diff<T: Integer>(l: T, r: T) -> T {
+    if l > r { l - r } else { r - l }
+}
+
+#[allow(dead_code)]
+impl<T: Integer> Point2<T> {
+    pub fn manhattan_distance(&self, other: &Point2<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Point2<T>) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// `None` when a coordinate does not fit in `U`, e.g. negative to `usize`.
+    pub fn try_cast<U: Integer>(&self) -> Option<Point2<U>> {
+        Some(Point2 { x: U::from(self.x)?, y: U::from(self.y)? })
+    }
+
+    pub fn to_tuple(self) -> (T, T) {
+        (self.x, self.y)
+    }
+}
+
+impl<T: Integer> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Integer> Display for Point2<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[allow(dead_code)]
+impl<T: Integer> Vec2<T> {
+    pub fn manhattan_len(&self) -> T
+    where
+        T: Signed,
+    {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn chebyshev_len(&self) -> T
+    where
+        T: Signed,
+    {
+        self.x.abs().max(self.y.abs())
+    }
+
+    /// Quarter turn clockwise as seen on screen, north becomes east.
+    pub fn rotate_cw_90(&self) -> Self
+    where
+        T: Signed,
+    {
+        Vec2 { x: -self.y, y: self.x }
+    }
+
+    /// Quarter turn counter clockwise as seen on screen, north becomes west.
+    pub fn rotate_ccw_90(&self) -> Self
+    where
+        T: Signed,
+    {
+        Vec2 { x: self.y, y: -self.x }
+    }
+}
+
+/// Unit vector, diagonals have both coordinates set.
+impl<T: Integer + Signed> From<GridDirection> for Vec2<T> {
+    fn from(direction: GridDirection) -> Self {
+        let (x, y) = match direction {
+            GridDirection::North => (0, -1),
+            GridDirection::South => (0, 1),
+            GridDirection::East => (1, 0),
+            GridDirection::West => (-1, 0),
+            GridDirection::SouthWest => (-1, 1),
+            GridDirection::SouthEast => (1, 1),
+            GridDirection::NorthEast => (1, -1),
+            GridDirection::NorthWest => (-1, -1),
+        };
+        Vec2 { x: T::from(x).unwrap(), y: T::from(y).unwrap() }
+    }
+}
+
+impl<T: Integer> Add<Vec2<T>> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, rhs: Vec2<T>) -> Self::Output {
+        Point2 { x: self.x + rhs.x, y: self.y + rhs.y }
+    }
+}
+
+impl<T: Integer> AddAssign<Vec2<T>> for Point2<T> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Integer> Sub<Vec2<T>> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, rhs: Vec2<T>) -> Self::Output {
+        Point2 { x: self.x - rhs.x, y: self.y - rhs.y }
+    }
+}
+
+impl<T: Integer> SubAssign<Vec2<T>> for Point2<T> {
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Integer> Sub for Point2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: Point2<T>) -> Self::Output {
+        Vec2 { x: self.x - rhs.x, y: self.y - rhs.y }
+    }
+}
+
+impl<T: Integer> Add for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, rhs: Vec2<T>) -> Self::Output {
+        Vec2 { x: self.x + rhs.x, y: self.y + rhs.y }
+    }
+}
+
+impl<T: Integer> Sub for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: Vec2<T>) -> Self::Output {
+        Vec2 { x: self.x - rhs.x, y: self.y - rhs.y }
+    }
+}
+
+impl<T: Integer> Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Vec2 { x: self.x * rhs, y: self.y * rhs }
+    }
+}
+
+impl<T: Integer + Signed> Neg for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn neg(self) -> Self::Output {
+        Vec2 { x: -self.x, y: -self.y }
+    }
+}
+
+#[allow(dead_code)]
+impl<T: Integer> Point3<T> {
+    pub fn manhattan_distance(&self, other: &Point3<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Point3<T>) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y)).max(abs_diff(self.z, other.z))
+    }
+
+    /// `None` when a coordinate does not fit in `U`, e.g. negative to `usize`.
+    pub fn try_cast<U: Integer>(&self) -> Option<Point3<U>> {
+        Some(Point3 { x: U::from(self.x)?, y: U::from(self.y)?, z: U::from(self.z)? })
+    }
+
+    pub fn to_tuple(self) -> (T, T, T) {
+        (self.x, self.y, self.z)
+    }
+}
+
+impl<T: Integer> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Integer> Display for Point3<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[allow(dead_code)]
+impl<T: Integer> Vec3<T> {
+    pub fn manhattan_len(&self) -> T
+    where
+        T: Signed,
+    {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    pub fn chebyshev_len(&self) -> T
+    where
+        T: Signed,
+    {
+        self.x.abs().max(self.y.abs()).max(self.z.abs())
+    }
+}
+
+impl<T: Integer> Add<Vec3<T>> for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, rhs: Vec3<T>) -> Self::Output {
+        Point3 { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+    }
+}
+
+impl<T: Integer> AddAssign<Vec3<T>> for Point3<T> {
+    fn add_assign(&mut self, rhs: Vec3<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Integer> Sub<Vec3<T>> for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, rhs: Vec3<T>) -> Self::Output {
+        Point3 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+    }
+}
+
+impl<T: Integer> SubAssign<Vec3<T>> for Point3<T> {
+    fn sub_assign(&mut self, rhs: Vec3<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Integer> Sub for Point3<T> {
+    type Output = Vec3<T>;
+
+    fn sub(self, rhs: Point3<T>) -> Self::Output {
+        Vec3 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+    }
+}
+
+impl<T: Integer> Add for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn add(self, rhs: Vec3<T>) -> Self::Output {
+        Vec3 { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+    }
+}
+
+impl<T: Integer> Sub for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn sub(self, rhs: Vec3<T>) -> Self::Output {
+        Vec3 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+    }
+}
+
+impl<T: Integer> Mul<T> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Vec3 { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs }
+    }
+}
+
+impl<T: Integer + Signed> Neg for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn neg(self) -> Self::Output {
+        Vec3 { x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::grid::point::{Point2, Point3, Vec2};
+    use crate::utils::grid::GridDirection;
+
+    #[test]
+    fn test_try_cast() {
+        assert_eq!(Point2::new(3_isize, 4).try_cast::<usize>(), Some(Point2::new(3_usize, 4)));
+        assert_eq!(Point2::new(3_isize, -1).try_cast::<usize>(), None);
+        assert_eq!(Point2::new(-1_isize, 0).try_cast::<usize>(), None);
+        assert_eq!(Point2::new(300_usize, 0).try_cast::<u8>(), None);
+        assert_eq!(Point3::new(0_isize, 0, -2).try_cast::<usize>(), None);
+        assert_eq!(Point3::new(-2_isize, 5, 1).try_cast::<i8>(), Some(Point3::new(-2_i8, 5, 1)));
+    }
+
+    #[test]
+    fn test_rotate() {
+        let north = Vec2::<isize>::from(GridDirection::North);
+        assert_eq!(north.rotate_cw_90(), Vec2::from(GridDirection::East));
+        assert_eq!(north.rotate_ccw_90(), Vec2::from(GridDirection::West));
+        for direction in GridDirection::CARDINAL {
+            let v = Vec2::<isize>::from(direction);
+            assert_eq!(v.rotate_cw_90(), Vec2::from(direction.clock_wise_90()));
+            assert_eq!(v.rotate_cw_90().rotate_ccw_90(), v);
+        }
+        let v = Vec2::new(2_isize, -3);
+        assert_eq!(v.rotate_cw_90(), Vec2::new(3, 2));
+        assert_eq!(v.rotate_cw_90().rotate_cw_90(), -v);
+        assert_eq!(v.rotate_ccw_90(), Vec2::new(-3, -2));
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point2::new(-2_isize, 3), Point2::new(4_isize, -1));
+        assert_eq!(a.manhattan_distance(&b), 10);
+        assert_eq!(a.chebyshev_distance(&b), 6);
+        assert_eq!((b - a).manhattan_len(), 10);
+        assert_eq!((a - b).chebyshev_len(), 6);
+        assert_eq!(Point2::new(5_usize, 1).manhattan_distance(&Point2::new(2, 7)), 9);
+        assert_eq!(Point2::new(5_usize, 1).chebyshev_distance(&Point2::new(2, 7)), 6);
+        let (a, b) = (Point3::new(1_isize, -2, 3), Point3::new(-1_isize, 2, 4));
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.manhattan_distance(&a), 0);
+    }
+}
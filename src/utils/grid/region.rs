@@ -0,0 +1,229 @@
+use std::collections::{HashSet, VecDeque};
+use std::ops::ControlFlow;
+
+use itertools::Itertools;
+
+use crate::utils::graph::bfs_full;
+use crate::utils::grid::grid_2d_vec::Grid2dVec;
+use crate::utils::grid::neighbor::Grid2dNeighbor;
+use crate::utils::grid::point::Vec2;
+use crate::utils::grid::{Grid2d, GridDirection};
+
+/// Cells connected through their north, south, east and west sides.
+#[derive(Clone, Default, Debug)]
+pub struct Region {
+    cells: HashSet<(usize, usize)>,
+}
+
+#[allow(dead_code)]
+impl Region {
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        self.cells.contains(&(x, y))
+    }
+
+    /// Cells in no particular order.
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.cells.iter().copied()
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Whether the side of `(x, y)` facing `direction` borders a cell outside
+    /// the region, the grid edge included.
+    fn is_edge(&self, x: usize, y: usize, direction: GridDirection) -> bool {
+        let offset = Vec2::<isize>::from(direction);
+        x.checked_add_signed(offset.x)
+            .zip(y.checked_add_signed(offset.y))
+            .is_none_or(|(x, y)| !self.contains(x, y))
+    }
+
+    /// Number of cell sides bordering a cell outside the region.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|&(x, y)| {
+                GridDirection::CARDINAL.iter().filter(|d| self.is_edge(x, y, **d)).count()
+            })
+            .sum()
+    }
+
+    /// Number of straight fences around the region, inner holes included.
+    /// Each fence is counted once at its end reached by turning clockwise
+    /// from the side it faces.
+    pub fn sides(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|&(x, y)| {
+                GridDirection::CARDINAL
+                    .iter()
+                    .filter(|d| self.is_edge(x, y, **d))
+                    .filter(|d| {
+                        let offset = Vec2::<isize>::from(d.clock_wise_90());
+                        x.checked_add_signed(offset.x)
+                            .zip(y.checked_add_signed(offset.y))
+                            .is_none_or(|(x, y)| !self.contains(x, y) || !self.is_edge(x, y, **d))
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Inclusive top left and bottom right corners, `None` for an empty
+    /// region.
+    pub fn bounding_box(&self) -> Option<((usize, usize), (usize, usize))> {
+        self.cells.iter().fold(None, |bounds, &(x, y)| match bounds {
+            None => Some(((x, y), (x, y))),
+            Some(((min_x, min_y), (max_x, max_y))) => {
+                Some(((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y))))
+            }
+        })
+    }
+}
+
+/// Result of [`Grid2dRegion::connected_components`].
+#[derive(Debug)]
+pub struct Components {
+    labels: Grid2dVec<usize>,
+    regions: Vec<Region>,
+}
+
+#[allow(dead_code)]
+impl Components {
+    /// Index in [`Components::regions`] of the region of each cell.
+    pub fn labels(&self) -> &Grid2dVec<usize> {
+        &self.labels
+    }
+
+    /// Ordered by their first cell in row major order.
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    pub fn region_of(&self, x: usize, y: usize) -> &Region {
+        &self.regions[self.labels[(x, y)]]
+    }
+}
+
+/// Region algorithms of any [`Grid2d`]. `same_region` is given 2 cardinal
+/// neighbors, the one already in the region first.
+#[allow(dead_code)]
+pub trait Grid2dRegion<T>: Grid2d<T> + Sized {
+    /// Region of `(x, y)`, whatever the value of that cell.
+    fn flood_fill<F: Fn(&T, &T) -> bool>(&self, x: usize, y: usize, same_region: F) -> Region {
+        let neighbor_fn = |&(x, y): &(usize, usize)| {
+            self.cardinal_neighbors(x, y)
+                .filter(|(_, _, _, t)| same_region(&self[(x, y)], t))
+                .map(|(x, y, _, _)| (x, y))
+                .collect_vec()
+        };
+        match bfs_full(
+            VecDeque::from([((), (x, y))]),
+            HashSet::default(),
+            neighbor_fn,
+            |_, _| false,
+            |_, _| (),
+        ) {
+            ControlFlow::Continue(cells) => Region { cells },
+            ControlFlow::Break(_) => unreachable!("end state is never reached"),
+        }
+    }
+
+    /// Split the whole grid into regions, every cell belongs to exactly one.
+    fn connected_components<F: Fn(&T, &T) -> bool>(&self, same_region: F) -> Components {
+        let mut labels = vec![None; self.size()];
+        let mut regions = Vec::new();
+        for (y, x) in (0..self.height()).cartesian_product(0..self.width()) {
+            if labels[y * self.width() + x].is_some() {
+                continue;
+            }
+            let region = self.flood_fill(x, y, &same_region);
+            region.cells().for_each(|(x, y)| labels[y * self.width() + x] = Some(regions.len()));
+            regions.push(region);
+        }
+        let labels = Grid2dVec::from_fn(self.width(), self.height(), |x, y| {
+            labels[y * self.width() + x].unwrap()
+        });
+        Components { labels, regions }
+    }
+}
+
+impl<T, G: Grid2d<T>> Grid2dRegion<T> for G {}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use itertools::Itertools;
+
+    use crate::utils::grid::grid_2d_vec::Grid2dVec;
+    use crate::utils::grid::region::Grid2dRegion;
+    use crate::utils::grid::Grid2d;
+
+    fn parse(s: &str) -> Result<Grid2dVec<char>> {
+        Grid2dVec::parse_with(s, |_, _, b| Ok(b as char))
+    }
+
+    #[test]
+    fn test_l_shape() -> Result<()> {
+        let grid = parse("A..\nA..\nAAA")?;
+        let region = grid.flood_fill(0, 0, |a, b| a == b);
+        assert_eq!(region.cells().sorted().collect_vec(), [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]);
+        assert_eq!(region.area(), 5);
+        assert_eq!(region.perimeter(), 12);
+        assert_eq!(region.sides(), 6);
+        assert_eq!(region.bounding_box(), Some(((0, 0), (2, 2))));
+
+        let rest = grid.flood_fill(2, 0, |a, b| a == b);
+        assert_eq!((rest.area(), rest.perimeter(), rest.sides()), (4, 8, 4));
+        assert_eq!(rest.bounding_box(), Some(((1, 0), (2, 1))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_hole() -> Result<()> {
+        let grid = parse("AAAA\nA..A\nAAAA")?;
+        let ring = grid.flood_fill(0, 0, |a, b| a == b);
+        assert_eq!(ring.area(), 10);
+        assert_eq!(ring.perimeter(), 14 + 6);
+        assert_eq!(ring.sides(), 4 + 4);
+        let hole = grid.flood_fill(1, 1, |a, b| a == b);
+        assert_eq!((hole.area(), hole.perimeter(), hole.sides()), (2, 6, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_diagonal_stays_separate() -> Result<()> {
+        let grid = parse("AB\nBA")?;
+        let region = grid.flood_fill(0, 0, |a, b| a == b);
+        assert_eq!(region.area(), 1);
+        assert!(!region.contains(1, 1));
+        let components = grid.connected_components(|a, b| a == b);
+        assert_eq!(components.regions().len(), 4);
+        assert_ne!(components.labels()[(0, 0)], components.labels()[(1, 1)]);
+        assert_ne!(components.labels()[(1, 0)], components.labels()[(0, 1)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_components_labels() -> Result<()> {
+        let grid = parse("AAB\nCAB\nCCB\nAAC")?;
+        let components = grid.connected_components(|a, b| a == b);
+        let regions = components.regions();
+        assert_eq!(regions.len(), 5);
+        assert_eq!(regions.iter().map(|region| region.area()).sum::<usize>(), grid.size());
+        for (y, x) in (0..grid.height()).cartesian_product(0..grid.width()) {
+            let label = components.labels()[(x, y)];
+            assert!(regions[label].contains(x, y));
+            let region = components.region_of(x, y);
+            assert!(region.cells().all(|(x, y)| components.labels()[(x, y)] == label));
+            assert_eq!(regions.iter().filter(|region| region.contains(x, y)).count(), 1);
+        }
+        // Regions are ordered by their first cell in row major order.
+        let firsts = regions.iter().map(|region| region.cells().map(|(x, y)| (y, x)).min());
+        assert!(firsts.is_sorted());
+        assert_eq!(components.region_of(0, 3).area(), 2);
+        assert_eq!(components.region_of(2, 3).area(), 1);
+        Ok(())
+    }
+}
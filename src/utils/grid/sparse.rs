@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::Index;
+
+use crate::utils::grid::grid_2d_vec::Grid2dVec;
+use crate::utils::grid::neighbor::direction_neighbors;
+use crate::utils::grid::point::{Point2, Vec2};
+use crate::utils::grid::{Grid2d, GridDirection};
+
+/// Unbounded grid storing only cells that were set, every other cell is
+/// `default`. The bounding box covers every cell ever set, it does not shrink
+/// on [`SparseGrid::remove`].
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2<isize>, T>,
+    default: T,
+    bounds: Option<(Point2<isize>, Point2<isize>)>,
+}
+
+#[allow(dead_code)]
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        SparseGrid { cells: HashMap::default(), default, bounds: None }
+    }
+
+    pub fn get(&self, point: Point2<isize>) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+
+    /// Set cell at `point`, returns the previous value if it was set.
+    pub fn set(&mut self, point: Point2<isize>, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                Point2::new(min.x.min(point.x), min.y.min(point.y)),
+                Point2::new(max.x.max(point.x), max.y.max(point.y)),
+            ),
+        });
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point2<isize>) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    pub fn contains(&self, point: Point2<isize>) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Number of cells set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Cells set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Point2<isize>, &T)> {
+        self.cells.iter()
+    }
+
+    /// Inclusive top left and bottom right corners, `None` if nothing was set.
+    pub fn bounds(&self) -> Option<(Point2<isize>, Point2<isize>)> {
+        self.bounds
+    }
+
+    pub fn neighbors_in<'a>(
+        &'a self,
+        point: Point2<isize>,
+        directions: &'a [GridDirection],
+    ) -> impl Iterator<Item = (Point2<isize>, GridDirection, &'a T)> + 'a {
+        direction_neighbors(directions.iter().copied(), move |offset| {
+            let next = point + offset;
+            Some((next, self.get(next)))
+        })
+    }
+
+    pub fn cardinal_neighbors(
+        &self,
+        point: Point2<isize>,
+    ) -> impl Iterator<Item = (Point2<isize>, GridDirection, &T)> {
+        self.neighbors_in(point, &GridDirection::CARDINAL)
+    }
+
+    pub fn all_neighbors(
+        &self,
+        point: Point2<isize>,
+    ) -> impl Iterator<Item = (Point2<isize>, GridDirection, &T)> {
+        self.neighbors_in(point, &GridDirection::ALL)
+    }
+
+    /// [`Grid2d`] view of the bounding box, `(0, 0)` is its top left corner.
+    pub fn bounded(&self) -> BoundedSparseGrid<'_, T> {
+        let (origin, size) = match self.bounds {
+            None => (Point2::default(), Vec2::default()),
+            Some((min, max)) => (min, max - min + Vec2::new(1, 1)),
+        };
+        BoundedSparseGrid { grid: self, origin, width: size.x as usize, height: size.y as usize }
+    }
+
+    /// Dense copy of the bounding box, with the top left corner it maps to.
+    pub fn to_grid_vec(&self) -> (Grid2dVec<T>, Point2<isize>)
+    where
+        T: Clone,
+    {
+        let bounded = self.bounded();
+        (Grid2dVec::from_grid(&bounded), bounded.origin)
+    }
+}
+
+/// See [`SparseGrid::bounded`].
+pub struct BoundedSparseGrid<'a, T> {
+    grid: &'a SparseGrid<T>,
+    origin: Point2<isize>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Index<(usize, usize)> for BoundedSparseGrid<'_, T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(self.contains(x, y));
+        self.grid.get(self.origin + Vec2::new(x as isize, y as isize))
+    }
+}
+
+impl<T> Grid2d<T> for BoundedSparseGrid<'_, T> {
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    /// Renders the bounding box, see [`Grid2d::display_cells`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.bounded().display_cells())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::utils::grid::point::Point2;
+    use crate::utils::grid::sparse::SparseGrid;
+    use crate::utils::grid::{Grid2d, GridDirection};
+
+    /// `#` at (-2, 1), (1, -1) and (0, 0).
+    fn sample() -> SparseGrid<char> {
+        let mut grid = SparseGrid::new('.');
+        grid.set(Point2::new(-2, 1), '#');
+        grid.set(Point2::new(1, -1), '#');
+        grid.set(Point2::new(0, 0), '#');
+        grid
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = sample();
+        assert_eq!(grid.bounds(), Some((Point2::new(-2, -1), Point2::new(1, 1))));
+        let bounded = grid.bounded();
+        assert_eq!((bounded.width(), bounded.height()), (4, 3));
+        assert_eq!(bounded[(0, 2)], '#');
+        assert_eq!(bounded[(1, 2)], '.');
+
+        assert_eq!(grid.remove(Point2::new(-2, 1)), Some('#'));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some((Point2::new(-2, -1), Point2::new(1, 1))));
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid = SparseGrid::new(0_u8);
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+        let bounded = grid.bounded();
+        assert_eq!((bounded.width(), bounded.height()), (0, 0));
+        let (dense, origin) = grid.to_grid_vec();
+        assert_eq!((dense.width(), dense.height(), origin), (0, 0, Point2::default()));
+        assert_eq!(grid.to_string(), "");
+    }
+
+    #[test]
+    fn test_to_grid_vec_and_display() {
+        let grid = sample();
+        let (dense, origin) = grid.to_grid_vec();
+        assert_eq!(origin, Point2::new(-2, -1));
+        assert_eq!(dense[(2, 1)], *grid.get(Point2::new(0, 0)));
+        assert_eq!(dense.to_string(), "...#\n..#.\n#...");
+        assert_eq!(grid.to_string(), dense.to_string());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = sample();
+        assert_eq!(
+            grid.cardinal_neighbors(Point2::new(1, 0))
+                .map(|(point, direction, &c)| (point.to_tuple(), direction, c))
+                .collect_vec(),
+            [
+                ((1, -1), GridDirection::North, '#'),
+                ((1, 1), GridDirection::South, '.'),
+                ((2, 0), GridDirection::East, '.'),
+                ((0, 0), GridDirection::West, '#'),
+            ]
+        );
+        let far = grid.all_neighbors(Point2::new(50, -50)).collect_vec();
+        assert_eq!(far.len(), 8);
+        assert!(far.iter().all(|(_, _, &c)| c == '.'));
+        assert_eq!(grid.all_neighbors(Point2::new(-1, 0)).filter(|(_, _, &c)| c == '#').count(), 2);
+    }
+}
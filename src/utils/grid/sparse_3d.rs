@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use std::ops::Index;
+
+use crate::utils::grid::grid_3d::{Grid3d, ALL_OFFSETS, FACE_OFFSETS};
+use crate::utils::grid::grid_3d_vec::Grid3dVec;
+use crate::utils::grid::point::{Point3, Vec3};
+
+/// 3D counterpart of [`crate::utils::grid::sparse::SparseGrid`], every cell
+/// not set is `default` and the bounding box only grows.
+#[derive(Clone, Debug)]
+pub struct SparseGrid3d<T> {
+    cells: HashMap<Point3<isize>, T>,
+    default: T,
+    bounds: Option<(Point3<isize>, Point3<isize>)>,
+}
+
+#[allow(dead_code)]
+impl<T> SparseGrid3d<T> {
+    pub fn new(default: T) -> Self {
+        SparseGrid3d { cells: HashMap::default(), default, bounds: None }
+    }
+
+    pub fn get(&self, point: Point3<isize>) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+
+    /// Set cell at `point`, returns the previous value if it was set.
+    pub fn set(&mut self, point: Point3<isize>, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                Point3::new(min.x.min(point.x), min.y.min(point.y), min.z.min(point.z)),
+                Point3::new(max.x.max(point.x), max.y.max(point.y), max.z.max(point.z)),
+            ),
+        });
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point3<isize>) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    pub fn contains(&self, point: Point3<isize>) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Number of cells set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Cells set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Point3<isize>, &T)> {
+        self.cells.iter()
+    }
+
+    /// Inclusive lowest and highest corners, `None` if nothing was set.
+    pub fn bounds(&self) -> Option<(Point3<isize>, Point3<isize>)> {
+        self.bounds
+    }
+
+    pub fn neighbors_in<'a>(
+        &'a self,
+        point: Point3<isize>,
+        offsets: &'a [Vec3<isize>],
+    ) -> impl Iterator<Item = (Point3<isize>, &'a T)> + 'a {
+        offsets.iter().map(move |offset| {
+            let next = point + *offset;
+            (next, self.get(next))
+        })
+    }
+
+    /// The 6 neighbors sharing a face.
+    pub fn face_neighbors(
+        &self,
+        point: Point3<isize>,
+    ) -> impl Iterator<Item = (Point3<isize>, &T)> {
+        self.neighbors_in(point, &FACE_OFFSETS)
+    }
+
+    /// The 26 neighbors sharing a face, an edge or a corner.
+    pub fn all_neighbors(&self, point: Point3<isize>) -> impl Iterator<Item = (Point3<isize>, &T)> {
+        self.neighbors_in(point, &ALL_OFFSETS)
+    }
+
+    /// [`Grid3d`] view of the bounding box, `(0, 0, 0)` is its lowest corner.
+    pub fn bounded(&self) -> BoundedSparseGrid3d<'_, T> {
+        let (origin, size) = match self.bounds {
+            None => (Point3::default(), Vec3::default()),
+            Some((min, max)) => (min, max - min + Vec3::new(1, 1, 1)),
+        };
+        BoundedSparseGrid3d {
+            grid: self,
+            origin,
+            width: size.x as usize,
+            height: size.y as usize,
+            depth: size.z as usize,
+        }
+    }
+
+    /// Dense copy of the bounding box, with the lowest corner it maps to.
+    pub fn to_grid_vec(&self) -> (Grid3dVec<T>, Point3<isize>)
+    where
+        T: Clone,
+    {
+        let bounded = self.bounded();
+        (Grid3dVec::from_grid(&bounded), bounded.origin)
+    }
+}
+
+/// See [`SparseGrid3d::bounded`].
+pub struct BoundedSparseGrid3d<'a, T> {
+    grid: &'a SparseGrid3d<T>,
+    origin: Point3<isize>,
+    width: usize,
+    height: usize,
+    depth: usize,
+}
+
+impl<T> Index<(usize, usize, usize)> for BoundedSparseGrid3d<'_, T> {
+    type Output = T;
+
+    fn index(&self, (x, y, z): (usize, usize, usize)) -> &Self::Output {
+        assert!(self.contains(x, y, z));
+        self.grid.get(self.origin + Vec3::new(x as isize, y as isize, z as isize))
+    }
+}
+
+impl<T> Grid3d<T> for BoundedSparseGrid3d<'_, T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::iproduct;
+
+    use crate::utils::grid::grid_3d::Grid3d;
+    use crate::utils::grid::point::Point3;
+    use crate::utils::grid::sparse_3d::SparseGrid3d;
+
+    /// `#` at (-2, 1, 0) and (1, -1, -3).
+    fn sample() -> SparseGrid3d<char> {
+        let mut grid = SparseGrid3d::new('.');
+        grid.set(Point3::new(-2, 1, 0), '#');
+        grid.set(Point3::new(1, -1, -3), '#');
+        grid
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid3d::new('.');
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.bounded().size(), 0);
+
+        grid.set(Point3::new(-2, 1, 0), '#');
+        assert_eq!(grid.bounds(), Some((Point3::new(-2, 1, 0), Point3::new(-2, 1, 0))));
+        grid.set(Point3::new(1, -1, -3), '#');
+        assert_eq!(grid.bounds(), Some((Point3::new(-2, -1, -3), Point3::new(1, 1, 0))));
+
+        assert_eq!(grid.remove(Point3::new(1, -1, -3)), Some('#'));
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.bounds(), Some((Point3::new(-2, -1, -3), Point3::new(1, 1, 0))));
+    }
+
+    #[test]
+    fn test_bounded_and_to_grid_vec() {
+        let grid = sample();
+        let bounded = grid.bounded();
+        assert_eq!((bounded.width(), bounded.height(), bounded.depth()), (4, 3, 4));
+        assert_eq!(bounded[(0, 2, 3)], '#');
+        assert_eq!(bounded[(3, 0, 0)], '#');
+        assert_eq!(bounded[(1, 1, 1)], '.');
+
+        let (dense, origin) = grid.to_grid_vec();
+        assert_eq!(origin, Point3::new(-2, -1, -3));
+        assert_eq!((dense.width(), dense.height(), dense.depth()), (4, 3, 4));
+        assert!(iproduct!(0..4, 0..3, 0..4).all(|(x, y, z)| dense[(x, y, z)] == bounded[(x, y, z)]));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = sample();
+        assert_eq!(grid.face_neighbors(Point3::new(50, -50, 0)).count(), 6);
+        assert_eq!(grid.all_neighbors(Point3::new(50, -50, 0)).count(), 26);
+        assert_eq!(
+            grid.face_neighbors(Point3::new(-2, 1, 1)).filter(|(_, &c)| c == '#').count(),
+            1
+        );
+        assert_eq!(
+            grid.face_neighbors(Point3::new(-1, 0, 0)).filter(|(_, &c)| c == '#').count(),
+            0
+        );
+        assert_eq!(grid.all_neighbors(Point3::new(-1, 0, 0)).filter(|(_, &c)| c == '#').count(), 1);
+    }
+
+    #[test]
+    fn test_exterior_surface_area() {
+        // Hollow 3x3x3 shell centered on (-5, -5, -5) and a lone voxel.
+        let mut grid = SparseGrid3d::new(false);
+        iproduct!(-6..=-4, -6..=-4, -6..=-4).filter(|point| *point != (-5, -5, -5)).for_each(
+            |(x, y, z)| {
+                grid.set(Point3::new(x, y, z), true);
+            },
+        );
+        grid.set(Point3::new(-1, -5, -5), true);
+
+        let bounded = grid.bounded();
+        assert_eq!(bounded.surface_area(|&b| b), 66);
+        assert_eq!(bounded.exterior_surface_area(|&b| b), 60);
+        assert_eq!(bounded.interior_surface_area(|&b| b), 6);
+    }
+}
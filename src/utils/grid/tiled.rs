@@ -0,0 +1,99 @@
+use std::marker::PhantomData;
+use std::ops::Index;
+
+use crate::utils::grid::neighbor::direction_neighbors;
+use crate::utils::grid::point::{Point2, Vec2};
+use crate::utils::grid::{Grid2d, GridDirection};
+
+/// A grid repeated infinitely in every direction. Tile `(0, 0)` is the grid
+/// itself, tile `(-1, 0)` the copy on its west and so on.
+pub struct TiledGrid<'a, T, G: Grid2d<T>> {
+    grid: &'a G,
+    _cell: PhantomData<T>,
+}
+
+#[allow(dead_code)]
+impl<'a, T: 'a, G: Grid2d<T>> TiledGrid<'a, T, G> {
+    /// `None` for an empty grid, which has nothing to tile.
+    pub fn new(grid: &'a G) -> Option<Self> {
+        (grid.width() > 0 && grid.height() > 0).then_some(TiledGrid { grid, _cell: PhantomData })
+    }
+
+    fn tile_size(&self) -> Vec2<isize> {
+        Vec2::new(self.grid.width() as isize, self.grid.height() as isize)
+    }
+
+    /// Tile containing `point`.
+    pub fn tile_of(&self, point: Point2<isize>) -> Point2<isize> {
+        let size = self.tile_size();
+        Point2::new(point.x.div_euclid(size.x), point.y.div_euclid(size.y))
+    }
+
+    /// Coordinate of `point` inside its tile, i.e. in the wrapped grid.
+    pub fn local_coordinate(&self, point: Point2<isize>) -> (usize, usize) {
+        let size = self.tile_size();
+        (point.x.rem_euclid(size.x) as usize, point.y.rem_euclid(size.y) as usize)
+    }
+
+    /// Top left cell of `tile`.
+    pub fn tile_origin(&self, tile: Point2<isize>) -> Point2<isize> {
+        let size = self.tile_size();
+        Point2::new(tile.x * size.x, tile.y * size.y)
+    }
+
+    pub fn get(&self, point: Point2<isize>) -> &'a T {
+        &self.grid[self.local_coordinate(point)]
+    }
+
+    /// North, south, east and west neighbors, there is always 4.
+    pub fn cardinal_neighbors(
+        &self,
+        point: Point2<isize>,
+    ) -> impl Iterator<Item = (Point2<isize>, GridDirection, &'a T)> + '_ {
+        direction_neighbors(GridDirection::CARDINAL, move |offset| {
+            let next = point + offset;
+            Some((next, self.get(next)))
+        })
+    }
+}
+
+impl<T, G: Grid2d<T>> Index<Point2<isize>> for TiledGrid<'_, T, G> {
+    type Output = T;
+
+    fn index(&self, point: Point2<isize>) -> &Self::Output {
+        self.get(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::grid::grid_2d_vec::Grid2dVec;
+    use crate::utils::grid::point::Point2;
+    use crate::utils::grid::tiled::TiledGrid;
+
+    #[test]
+    fn test_negative_coordinates() {
+        let grid = Grid2dVec::from_fn(3, 2, |x, y| (x, y));
+        let tiled = TiledGrid::new(&grid).unwrap();
+
+        let point = Point2::new(-1, -1);
+        assert_eq!(tiled.tile_of(point), Point2::new(-1, -1));
+        assert_eq!(tiled.local_coordinate(point), (2, 1));
+        assert_eq!(tiled.tile_origin(Point2::new(-1, -1)), Point2::new(-3, -2));
+        assert_eq!(tiled[point], (2, 1));
+
+        let point = Point2::new(-4, 5);
+        assert_eq!(tiled.tile_of(point), Point2::new(-2, 2));
+        assert_eq!(tiled.local_coordinate(point), (2, 1));
+        assert_eq!(tiled.tile_origin(tiled.tile_of(point)), Point2::new(-6, 4));
+        assert_eq!(tiled.tile_of(Point2::new(2, 1)), Point2::new(0, 0));
+    }
+
+    #[test]
+    fn test_empty_grid() {
+        let grid = Grid2dVec::<u8>::from_fn(0, 0, |_, _| 0);
+        assert!(TiledGrid::new(&grid).is_none());
+        let grid = Grid2dVec::<u8>::from_fn(3, 0, |_, _| 0);
+        assert!(TiledGrid::new(&grid).is_none());
+    }
+}
@@ -0,0 +1,251 @@
+use std::marker::PhantomData;
+use std::ops::Index;
+
+use crate::utils::grid::Grid2d;
+
+/// Where a view cell is read from: first swap x and y if `transpose`, then
+/// mirror each axis of the underlying grid. The 8 combinations cover every
+/// rotation and flip.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Debug)]
+pub struct Orientation {
+    pub transpose: bool,
+    pub flip_x: bool,
+    pub flip_y: bool,
+}
+
+impl Orientation {
+    pub const TRANSPOSE: Orientation = Orientation::new(true, false, false);
+    /// Clockwise.
+    pub const ROTATE_90: Orientation = Orientation::new(true, false, true);
+    pub const ROTATE_180: Orientation = Orientation::new(false, true, true);
+    /// Clockwise, same as 90 counter clockwise.
+    pub const ROTATE_270: Orientation = Orientation::new(true, true, false);
+    /// Mirror left and right.
+    pub const FLIP_HORIZONTAL: Orientation = Orientation::new(false, true, false);
+    /// Mirror top and bottom.
+    pub const FLIP_VERTICAL: Orientation = Orientation::new(false, false, true);
+
+    pub const fn new(transpose: bool, flip_x: bool, flip_y: bool) -> Self {
+        Orientation { transpose, flip_x, flip_y }
+    }
+
+    /// Coordinate in a `width` x `height` grid of cell `(x, y)` of the view.
+    pub fn source_coordinate(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> (usize, usize) {
+        let (x, y) = if self.transpose { (y, x) } else { (x, y) };
+        (if self.flip_x { width - 1 - x } else { x }, if self.flip_y { height - 1 - y } else { y })
+    }
+}
+
+/// Zero-copy rotated, flipped or transposed view of a grid.
+pub struct OrientedGrid<'a, T, G: Grid2d<T>> {
+    grid: &'a G,
+    orientation: Orientation,
+    _cell: PhantomData<T>,
+}
+
+impl<T, G: Grid2d<T>> Index<(usize, usize)> for OrientedGrid<'_, T, G> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(self.contains(x, y));
+        &self.grid[self.orientation.source_coordinate(x, y, self.grid.width(), self.grid.height())]
+    }
+}
+
+impl<T, G: Grid2d<T>> Grid2d<T> for OrientedGrid<'_, T, G> {
+    fn height(&self) -> usize {
+        if self.orientation.transpose { self.grid.width() } else { self.grid.height() }
+    }
+
+    fn width(&self) -> usize {
+        if self.orientation.transpose { self.grid.height() } else { self.grid.width() }
+    }
+}
+
+/// Zero-copy rectangle of a grid, cell `(0, 0)` is `(x, y)` of the grid.
+pub struct SubGrid<'a, T, G: Grid2d<T>> {
+    grid: &'a G,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    _cell: PhantomData<T>,
+}
+
+impl<T, G: Grid2d<T>> Index<(usize, usize)> for SubGrid<'_, T, G> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(self.contains(x, y));
+        &self.grid[(self.x + x, self.y + y)]
+    }
+}
+
+impl<T, G: Grid2d<T>> Grid2d<T> for SubGrid<'_, T, G> {
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn width(&self) -> usize {
+        self.width
+    }
+}
+
+/// Zero-copy view of a grid surrounded by `padding` cells of `border` on every
+/// side.
+pub struct PaddedGrid<'a, T, G: Grid2d<T>> {
+    grid: &'a G,
+    padding: usize,
+    border: T,
+}
+
+impl<T, G: Grid2d<T>> Index<(usize, usize)> for PaddedGrid<'_, T, G> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(self.contains(x, y));
+        match (x.checked_sub(self.padding), y.checked_sub(self.padding)) {
+            (Some(x), Some(y)) if self.grid.contains(x, y) => &self.grid[(x, y)],
+            _ => &self.border,
+        }
+    }
+}
+
+impl<T, G: Grid2d<T>> Grid2d<T> for PaddedGrid<'_, T, G> {
+    fn height(&self) -> usize {
+        self.grid.height() + 2 * self.padding
+    }
+
+    fn width(&self) -> usize {
+        self.grid.width() + 2 * self.padding
+    }
+}
+
+/// Views of any [`Grid2d`], including views themselves. Materialize one with
+/// [`crate::utils::grid::grid_2d_vec::Grid2dVec::from_grid`] or
+/// [`crate::utils::grid::grid_2d_bitvec::Grid2dBitVec::from_grid`].
+#[allow(dead_code)]
+pub trait Grid2dTransform<T>: Grid2d<T> + Sized {
+    fn oriented(&self, orientation: Orientation) -> OrientedGrid<'_, T, Self> {
+        OrientedGrid { grid: self, orientation, _cell: PhantomData }
+    }
+
+    fn transposed(&self) -> OrientedGrid<'_, T, Self> {
+        self.oriented(Orientation::TRANSPOSE)
+    }
+
+    fn rotated_90(&self) -> OrientedGrid<'_, T, Self> {
+        self.oriented(Orientation::ROTATE_90)
+    }
+
+    fn rotated_180(&self) -> OrientedGrid<'_, T, Self> {
+        self.oriented(Orientation::ROTATE_180)
+    }
+
+    fn rotated_270(&self) -> OrientedGrid<'_, T, Self> {
+        self.oriented(Orientation::ROTATE_270)
+    }
+
+    fn flipped_horizontal(&self) -> OrientedGrid<'_, T, Self> {
+        self.oriented(Orientation::FLIP_HORIZONTAL)
+    }
+
+    fn flipped_vertical(&self) -> OrientedGrid<'_, T, Self> {
+        self.oriented(Orientation::FLIP_VERTICAL)
+    }
+
+    /// `None` if the rectangle does not fit in the grid.
+    fn cropped(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<SubGrid<'_, T, Self>> {
+        (x.checked_add(width)? <= self.width() && y.checked_add(height)? <= self.height())
+            .then_some(SubGrid { grid: self, x, y, width, height, _cell: PhantomData })
+    }
+
+    fn padded(&self, padding: usize, border: T) -> PaddedGrid<'_, T, Self> {
+        PaddedGrid { grid: self, padding, border }
+    }
+}
+
+impl<T, G: Grid2d<T>> Grid2dTransform<T> for G {}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::utils::grid::grid_2d_vec::Grid2dVec;
+    use crate::utils::grid::transform::{Grid2dTransform, Orientation};
+    use crate::utils::grid::Grid2d;
+
+    fn render<G: Grid2d<char>>(grid: &G) -> String {
+        grid.display_with(|_, _, c| *c).to_string()
+    }
+
+    fn sample() -> Result<Grid2dVec<char>> {
+        Grid2dVec::parse_with("abc\ndef", |_, _, b| Ok(b as char))
+    }
+
+    #[test]
+    fn test_orientations() -> Result<()> {
+        let grid = sample()?;
+        let cases = [
+            (Orientation::default(), "abc\ndef"),
+            (Orientation::TRANSPOSE, "ad\nbe\ncf"),
+            (Orientation::ROTATE_90, "da\neb\nfc"),
+            (Orientation::ROTATE_180, "fed\ncba"),
+            (Orientation::ROTATE_270, "cf\nbe\nad"),
+            (Orientation::FLIP_HORIZONTAL, "cba\nfed"),
+            (Orientation::FLIP_VERTICAL, "def\nabc"),
+            (Orientation::new(true, true, true), "fc\neb\nda"),
+        ];
+        for (orientation, expected) in cases {
+            let view = grid.oriented(orientation);
+            assert_eq!(render(&view), expected, "{:?}", orientation);
+            let (width, height) = if orientation.transpose { (2, 3) } else { (3, 2) };
+            assert_eq!((view.width(), view.height()), (width, height), "{:?}", orientation);
+            assert_eq!(view.get(width, 0), None);
+            assert_eq!(view.get(0, height), None);
+        }
+        assert_eq!(render(&grid.rotated_90().rotated_90()), render(&grid.rotated_180()));
+        assert_eq!(render(&grid.transposed().transposed()), render(&grid));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cropped() -> Result<()> {
+        let grid = sample()?;
+        let sub = grid.cropped(1, 0, 2, 2).unwrap();
+        assert_eq!(render(&sub), "bc\nef");
+        assert_eq!((sub.width(), sub.height()), (2, 2));
+        assert_eq!(sub.get(2, 0), None);
+        assert_eq!(render(&grid.cropped(2, 1, 1, 1).unwrap()), "f");
+        assert_eq!(grid.cropped(3, 2, 0, 0).map(|sub| sub.size()), Some(0));
+        assert!(grid.cropped(2, 1, 2, 1).is_none());
+        assert!(grid.cropped(0, 1, 1, 2).is_none());
+        assert!(grid.cropped(usize::MAX, 0, 2, 1).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_padded() -> Result<()> {
+        let grid = sample()?;
+        let padded = grid.padded(1, '.');
+        assert_eq!(render(&padded), ".....\n.abc.\n.def.\n.....");
+        assert_eq!((padded.width(), padded.height()), (5, 4));
+        assert_eq!(padded.get(5, 0), None);
+        assert_eq!(render(&grid.padded(0, '.')), "abc\ndef");
+        assert_eq!(render(&padded.cropped(0, 0, 2, 2).unwrap()), "..\n.a");
+        assert_eq!(render(&padded.rotated_90()), "....\n.da.\n.eb.\n.fc.\n....");
+        Ok(())
+    }
+}
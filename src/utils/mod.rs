@@ -1,57 +1,86 @@
+#[cfg(feature = "solvers")]
 use std::fmt::Formatter;
-use std::fs;
-use std::fs::{create_dir_all, read_to_string, File};
-use std::path::{Path, PathBuf};
+#[cfg(feature = "y2023")]
 use std::sync::OnceLock;
 
-use anyhow::Context;
 use anyhow::Result;
-use derive_more::{Deref, Display};
+use derive_more::Display;
+#[cfg(feature = "y2023")]
+use derive_more::Deref;
+#[cfg(feature = "solvers")]
 use derive_new::new;
+#[cfg(feature = "y2023")]
 use regex::Regex;
-use reqwest::blocking::Client;
+#[cfg(feature = "y2023")]
 use thiserror::Error;
+#[cfg(feature = "solvers")]
+use tracing::{debug_span, info_span};
 
+use crate::input::InputProvider;
+#[cfg(feature = "solvers")]
 use crate::solver::ProblemSolver;
 
+// Only 2023 solvers search graphs and walk grids.
+#[cfg(feature = "y2023")]
 pub mod graph;
+#[cfg(feature = "y2023")]
 pub mod grid;
+#[cfg(feature = "y2023")]
 pub mod int_range;
+#[cfg(feature = "y2023")]
 pub mod int_trait;
 
+#[cfg(feature = "solvers")]
+/// Registry entry ([`crate::solver::DaySolver`]) of a solver type.
 macro_rules! boxed_try_get_input_and_solve {
     ($solver:ty) => {
-        |year, day, base_input_path, session_file_path| {
-            crate::utils::try_get_input_and_solve::<$solver, _>(
-                year,
-                day,
-                base_input_path,
-                session_file_path,
-            )
-            .map(|r| Box::new(r) as Box<dyn Display>)
+        crate::solver::DaySolver {
+            solve: |year, day, input_provider| {
+                crate::utils::try_get_input_and_solve::<$solver, _>(year, day, input_provider)
+                    .map(|r| Box::new(r) as Box<dyn std::fmt::Display>)
+            },
+            solve_parts: crate::utils::parse_and_solve_parts::<$solver>,
         }
     };
 }
 
+#[cfg(feature = "solvers")]
 pub(crate) use boxed_try_get_input_and_solve;
 
+#[cfg(feature = "y2023")]
+macro_rules! parse_and_visualize {
+    ($visualizer:ty) => {
+        |input, sink| {
+            use crate::visualize::Visualize;
+            <$visualizer as std::str::FromStr>::from_str(input)?.visualize(sink)
+        }
+    };
+}
+
+#[cfg(feature = "y2023")]
+pub(crate) use parse_and_visualize;
+
+#[cfg(feature = "y2023")]
 pub fn get_double_newline_regex() -> &'static Regex {
     static DOUBLE_NEWLINE_REGEX: OnceLock<Regex> = OnceLock::new();
     return DOUBLE_NEWLINE_REGEX.get_or_init(|| Regex::new(r"[ \t]*\r?\n[ \t]*\r?\n").unwrap());
 }
 
+#[cfg(feature = "y2023")]
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("Failed to split with delimiter {1:?}: {0:?}")]
     FailedToSplit(String, char),
 }
 
+#[cfg(feature = "solvers")]
 #[derive(Debug, Eq, PartialEq, new)]
 pub struct Result2Parts<T1: Display, T2: Display> {
     res_1: T1,
     res_2: T2,
 }
 
+#[cfg(feature = "y2023")]
 #[derive(new, Deref, Debug, Eq, PartialEq)]
 pub struct WarningResult<T> {
     #[deref]
@@ -59,98 +88,48 @@ pub struct WarningResult<T> {
     warning: &'static str,
 }
 
+#[cfg(feature = "solvers")]
 impl<T1: Display, T2: Display> Display for Result2Parts<T1, T2> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "<part 1: {}, part 2: {}>", self.res_1, self.res_2)
     }
 }
 
+#[cfg(feature = "y2023")]
 impl<T: Display> Display for WarningResult<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} --{}--", self.res, self.warning)
     }
 }
 
-fn reqwest_client() -> &'static Client {
-    static REQWEST_CLIENT: OnceLock<Client> = OnceLock::new();
-    return REQWEST_CLIENT.get_or_init(Client::new);
-}
-
-fn get_input_path(base_input_path: &Path, year: u16, day: u8) -> PathBuf {
-    base_input_path.join(format!("y{}/day{}.txt", year, day))
-}
-
-pub fn download_input_if_needed(
-    year: u16,
-    day: u8,
-    target_path: &Path,
-    session_cookie_path: &Path,
-) -> Result<()> {
-    if target_path.exists() {
-        if target_path.is_file() {
-            return Ok(());
-        } else {
-            anyhow::bail!(format!("Path is not a file: {:?}", target_path));
-        }
-    }
-
-    let session = read_to_string(session_cookie_path)
-        .with_context(|| format!("Failed to read session file: {:?}", session_cookie_path))?;
-
-    let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
-    let mut response = reqwest_client()
-        .get(&url)
-        .header("cookie", format!("session={}", session))
-        .send()
-        .with_context(|| format!("Failed to send get request to {}", url))?
-        .error_for_status()?;
-    create_dir_all(
-        target_path
-            .parent()
-            .with_context(|| format!("Failed to get parent for path {:?}", target_path))?,
-    )
-    .with_context(|| format!("Failed to create parent dir for path {:?}", target_path))?;
-
-    let mut output = File::create(target_path)
-        .with_context(|| format!("Failed to create file path {:?}", target_path))?;
-    let write_result = response.copy_to(&mut output);
-    match write_result {
-        Ok(_) => Ok(()),
-        Err(e) => {
-            fs::remove_file(target_path).with_context(|| {
-                format!("Input file write failed but cannot delete for file path {:?}", target_path)
-            })?;
-            Err(e).with_context(|| format!("Input file write failed {:?}", target_path))?
-        }
-    }
-}
-
 pub trait GetInputAndSolver<T: Display> {
-    fn try_get_input_and_solve(
-        year: u16,
-        day: u8,
-        base_input_path: &Path,
-        session_file_path: &Path,
-    ) -> Result<T>;
+    fn try_get_input_and_solve(year: u16, day: u8, input_provider: &dyn InputProvider)
+    -> Result<T>;
 }
 
+#[cfg(feature = "solvers")]
 pub fn try_get_input_and_solve<P: ProblemSolver<SolutionType = T>, T: Display>(
     year: u16,
     day: u8,
-    base_input_path: &Path,
-    session_file_path: &Path,
+    input_provider: &dyn InputProvider,
 ) -> Result<T> {
-    let input = get_input(year, day, base_input_path, session_file_path)?;
-    P::from_str(&input)?.solve()
+    let _span = info_span!("day", year, day).entered();
+    let input = debug_span!("input").in_scope(|| input_provider.require_input(year, day))?;
+    let solver = debug_span!("parse", bytes = input.len()).in_scope(|| P::from_str(&input))?;
+    debug_span!("solve").in_scope(|| solver.solve())
 }
 
-pub fn get_input(
-    year: u16,
-    day: u8,
-    base_input_path: &Path,
-    session_file_path: &Path,
-) -> Result<String> {
-    let input_path = get_input_path(base_input_path, year, day);
-    download_input_if_needed(year, day, &input_path, session_file_path)?;
-    Ok(read_to_string(&input_path)?)
+#[cfg(feature = "solvers")]
+pub fn parse_and_solve_parts<P: ProblemSolver>(
+    input: &str,
+    part: Option<u8>,
+) -> Result<Vec<(u8, Box<dyn Display>)>> {
+    let solver = debug_span!("parse", bytes = input.len()).in_scope(|| P::from_str(input))?;
+    let parts = match part {
+        Some(part) => part..=part,
+        None => 1..=P::PARTS,
+    };
+    parts
+        .map(|part| Ok((part, debug_span!("part", part).in_scope(|| solver.solve_part(part))?)))
+        .collect()
 }
@@ -0,0 +1,238 @@
+use std::fs::{create_dir_all, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+
+// Only 2023 days register visualizers, without them no frame is ever built
+// or pushed.
+#[cfg_attr(not(feature = "y2023"), allow(dead_code))]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+#[cfg_attr(not(feature = "y2023"), allow(dead_code))]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Cell {
+    pub ch: char,
+    pub color: Rgb,
+}
+
+#[cfg_attr(not(feature = "y2023"), allow(dead_code))]
+/// One intermediate state of a solver, a grid of colored characters.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Frame {
+    width: usize,
+    cells: Vec<Cell>,
+}
+
+#[cfg_attr(not(feature = "y2023"), allow(dead_code))]
+impl Frame {
+    pub fn new(width: usize, height: usize, fill: Cell) -> Self {
+        Self { width, cells: vec![fill; width * height] }
+    }
+
+    /// Frame from a solver's text rendering, short lines are padded with
+    /// spaces.
+    pub fn from_text<F: Fn(char) -> Rgb>(text: &str, color_fn: F) -> Self {
+        let width = text.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+        let cells = text
+            .lines()
+            .flat_map(|line| line.chars().chain(std::iter::repeat(' ')).take(width))
+            .map(|ch| Cell { ch, color: color_fn(ch) })
+            .collect();
+        Self { width, cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len().checked_div(self.width).unwrap_or(0)
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, cell: Cell) {
+        self.cells[y * self.width + x] = cell;
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.cells.chunks(self.width.max(1))
+    }
+}
+
+#[cfg_attr(not(feature = "y2023"), allow(dead_code))]
+/// Colors used by [`Frame::from_text`] when a solver has no palette of its
+/// own: walls light, empty space dark, everything else highlighted.
+pub fn default_color(ch: char) -> Rgb {
+    match ch {
+        '#' => Rgb(200, 200, 200),
+        '.' | ' ' => Rgb(40, 40, 40),
+        _ => Rgb(255, 200, 0),
+    }
+}
+
+#[cfg_attr(not(feature = "y2023"), allow(dead_code))]
+/// Receive frames emitted by a [`Visualize`] implementation.
+pub trait FrameSink {
+    fn push(&mut self, frame: &Frame) -> Result<()>;
+}
+
+#[cfg_attr(not(feature = "y2023"), allow(dead_code))]
+/// Solver that can emit intermediate states, registered per day in the
+/// year's visualizer map.
+pub trait Visualize {
+    fn visualize(&self, sink: &mut dyn FrameSink) -> Result<()>;
+}
+
+#[derive(ValueEnum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum VisualizeFormat {
+    /// Colored terminal animation.
+    Ansi,
+    /// Plain text frames separated by an empty line.
+    Text,
+    /// One "frame_{index}.ppm" image per frame.
+    Ppm,
+}
+
+pub struct TextSink<W: Write>(W);
+
+impl<W: Write> FrameSink for TextSink<W> {
+    fn push(&mut self, frame: &Frame) -> Result<()> {
+        for row in frame.rows() {
+            writeln!(self.0, "{}", row.iter().map(|cell| cell.ch).collect::<String>())?;
+        }
+        writeln!(self.0)?;
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(feature = "y2023"), allow(dead_code))]
+pub struct AnsiSink<W: Write> {
+    writer: W,
+    delay: Duration,
+}
+
+impl<W: Write> FrameSink for AnsiSink<W> {
+    fn push(&mut self, frame: &Frame) -> Result<()> {
+        // Clear screen and move cursor to top left.
+        let mut out = String::from("\x1b[2J\x1b[H");
+        for row in frame.rows() {
+            for cell in row {
+                let Rgb(r, g, b) = cell.color;
+                out.push_str(&format!("\x1b[38;2;{};{};{}m{}", r, g, b, cell.ch));
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        self.writer.write_all(out.as_bytes())?;
+        self.writer.flush()?;
+        sleep(self.delay);
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(feature = "y2023"), allow(dead_code))]
+pub struct PpmSink {
+    folder: PathBuf,
+    scale: usize,
+    index: usize,
+}
+
+impl FrameSink for PpmSink {
+    fn push(&mut self, frame: &Frame) -> Result<()> {
+        let path = self.folder.join(format!("frame_{:05}.ppm", self.index));
+        let mut writer = BufWriter::new(
+            File::create(&path).with_context(|| format!("Failed to create frame {:?}", path))?,
+        );
+        writer.write_all(ppm_bytes(frame, self.scale).as_slice())?;
+        self.index += 1;
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(feature = "y2023"), allow(dead_code))]
+/// Binary PPM (P6) image of `frame`, each cell drawn as a `scale` pixels
+/// square.
+pub fn ppm_bytes(frame: &Frame, scale: usize) -> Vec<u8> {
+    let mut bytes =
+        format!("P6\n{} {}\n255\n", frame.width() * scale, frame.height() * scale).into_bytes();
+    for row in frame.rows() {
+        let line = row
+            .iter()
+            .flat_map(|cell| {
+                let Rgb(r, g, b) = cell.color;
+                [r, g, b].repeat(scale)
+            })
+            .collect::<Vec<_>>();
+        (0..scale).for_each(|_| bytes.extend_from_slice(&line));
+    }
+    bytes
+}
+
+/// Sink writing to `output` (a file for text formats, default stdout, or a
+/// folder for images, default "frames").
+pub fn new_sink(
+    format: VisualizeFormat,
+    output: Option<&Path>,
+    delay: Duration,
+    scale: usize,
+) -> Result<Box<dyn FrameSink>> {
+    let writer = || -> Result<Box<dyn Write>> {
+        Ok(match output {
+            None => Box::new(std::io::stdout()),
+            Some(path) => Box::new(BufWriter::new(
+                File::create(path).with_context(|| format!("Failed to create {:?}", path))?,
+            )),
+        })
+    };
+    Ok(match format {
+        VisualizeFormat::Ansi => Box::new(AnsiSink { writer: writer()?, delay }),
+        VisualizeFormat::Text => Box::new(TextSink(writer()?)),
+        VisualizeFormat::Ppm => {
+            let folder = output.map_or_else(|| PathBuf::from("frames"), Path::to_path_buf);
+            create_dir_all(&folder)
+                .with_context(|| format!("Failed to create frame folder {:?}", folder))?;
+            Box::new(PpmSink { folder, scale, index: 0 })
+        }
+    })
+}
+
+/// Collect frames in memory.
+#[cfg(test)]
+#[derive(Default, Debug)]
+pub struct VecSink(pub Vec<Frame>);
+
+#[cfg(test)]
+impl FrameSink for VecSink {
+    fn push(&mut self, frame: &Frame) -> Result<()> {
+        self.0.push(frame.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::visualize::{default_color, ppm_bytes, Frame, FrameSink, Rgb, TextSink};
+
+    #[test]
+    fn test_text_sink() -> Result<()> {
+        let mut out = Vec::new();
+        TextSink(&mut out).push(&Frame::from_text("#.\n#", default_color))?;
+        assert_eq!(String::from_utf8(out)?, "#.\n# \n\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_ppm_bytes() {
+        let frame =
+            Frame::from_text("#.", |ch| if ch == '#' { Rgb(1, 2, 3) } else { Rgb(4, 5, 6) });
+        let bytes = ppm_bytes(&frame, 2);
+        let (header, pixels) = bytes.split_at(b"P6\n4 2\n255\n".len());
+        assert_eq!(header, b"P6\n4 2\n255\n");
+        assert_eq!(pixels, [1, 2, 3, 1, 2, 3, 4, 5, 6, 4, 5, 6].repeat(2).as_slice());
+    }
+}